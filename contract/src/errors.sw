@@ -10,3 +10,8 @@ pub enum ValueError {
     ZeroValue: (),
     BadIntValue: u8,
 }
+
+pub enum PauseError {
+    Paused: (),
+    AssetPaused: AssetId,
+}
@@ -16,3 +16,13 @@ pub struct AssetMinted {
     pub amount: u64,
     pub minter: Identity,
 }
+
+pub struct Paused {
+    pub asset: Option<AssetId>,
+    pub sender: Identity,
+}
+
+pub struct Unpaused {
+    pub asset: Option<AssetId>,
+    pub sender: Identity,
+}
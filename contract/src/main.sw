@@ -14,6 +14,12 @@ use sway_libs::asset::base::{
     _total_supply,
 };
 use sway_libs::{
+    asset::metadata::{
+        _metadata,
+        _set_metadata,
+        SetAssetMetadata,
+        StorageMetadata,
+    },
     asset::supply::{
         _burn,
         _mint,
@@ -27,6 +33,7 @@ use sway_libs::{
 };
 use standards::src20::SRC20;
 use standards::src5::{SRC5, State};
+use standards::src7::{Metadata, SRC7};
 use std::{hash::{Hash, sha256}, storage::storage_string::*, string::String};
 use errors::*;
 use events::*;
@@ -40,6 +47,9 @@ storage {
     sub_id: StorageMap<AssetId, SubId> = StorageMap {},
     asset: StorageMap<b256, AssetId> = StorageMap {},
     restricted_mint: StorageMap<AssetId, bool> = StorageMap {},
+    paused: bool = false,
+    asset_paused: StorageMap<AssetId, bool> = StorageMap {},
+    metadata: StorageMetadata = StorageMetadata {},
 }
 
 abi MultiAsset {
@@ -57,9 +67,24 @@ abi MultiAsset {
         restricted_mint: bool,
     ) -> AssetId;
 
+    #[storage(read, write)]
+    fn asset_new_batch(assets: Vec<(String, String, u8, bool)>) -> Vec<AssetId>;
+
     #[storage(read, write)]
     fn mint(recipient: Identity, asset: AssetId, amount: u64);
 
+    #[storage(read, write)]
+    fn mint_batch(mints: Vec<(Identity, AssetId, u64)>);
+
+    #[storage(read, write)]
+    fn pause(asset: Option<AssetId>);
+
+    #[storage(read, write)]
+    fn unpause(asset: Option<AssetId>);
+
+    #[storage(read)]
+    fn is_paused(asset: Option<AssetId>) -> bool;
+
     #[storage(read)]
     fn asset(symbol: String) -> Option<AssetId>;
 
@@ -101,6 +126,30 @@ impl SRC5 for Contract {
     }
 }
 
+impl SRC7 for Contract {
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        _metadata(storage.metadata, asset, key)
+    }
+}
+
+impl SetAssetMetadata for Contract {
+    #[storage(read, write)]
+    fn set_metadata(asset: AssetId, key: String, metadata: Metadata) {
+        only_owner();
+        require(
+            storage
+                .sub_id
+                .get(asset)
+                .try_read()
+                .is_some(),
+            AssetError::AssetNotFound(asset),
+        );
+        require(key.as_bytes().len() > 0, ValueError::ZeroStringLength);
+        _set_metadata(storage.metadata, asset, key, metadata);
+    }
+}
+
 impl MultiAsset for Contract {
     #[storage(read, write)]
     fn initialize_ownership(new_owner: Identity) {
@@ -119,74 +168,85 @@ impl MultiAsset for Contract {
         decimals: u8,
         restricted_mint: bool,
     ) -> AssetId {
-        only_owner();
-        let sub_id = sha256((ContractId::this(), symbol));
-        let asset = AssetId::new(ContractId::this(), sub_id);
-        require(
-            name
-                .as_bytes()
-                .len() > 0 && symbol
-                .as_bytes()
-                .len() > 0,
-            ValueError::ZeroStringLength,
-        );
-        require(decimals <= 12, ValueError::BadIntValue(decimals));
-        require(
-            storage
-                .sub_id
-                .get(asset)
-                .try_read()
-                .is_none(),
-            AssetError::AssetAlreadyExists(asset),
-        );
-        _set_name(storage.name, asset, name);
-        _set_symbol(storage.symbol, asset, symbol);
-        _set_decimals(storage.decimals, asset, decimals);
-
-        storage.total_assets.write(storage.total_assets.read() + 1);
-        storage.total_supply.insert(asset, 0);
-        storage.sub_id.insert(asset, sub_id);
-        storage.asset.insert(sha256(symbol), asset);
-        storage.restricted_mint.insert(asset, restricted_mint);
+        create_asset(name, symbol, decimals, restricted_mint)
+    }
 
-        let creator = msg_sender().unwrap();
-        log(AssetNew {
-            asset,
-            name,
-            symbol,
-            decimals,
-            creator,
-        });
-        asset
+    #[storage(read, write)]
+    fn asset_new_batch(assets: Vec<(String, String, u8, bool)>) -> Vec<AssetId> {
+        require(assets.len() > 0, ValueError::ZeroValue);
+        let mut created = Vec::new();
+        for asset in assets.iter() {
+            let (name, symbol, decimals, restricted_mint) = asset;
+            created.push(create_asset(name, symbol, decimals, restricted_mint));
+        }
+        created
     }
 
     #[storage(read, write)]
     fn mint(recipient: Identity, asset: AssetId, amount: u64) {
-        let sub_id = storage.sub_id.get(asset).try_read();
-        require(amount > 0, ValueError::ZeroValue);
-        require(sub_id.is_some(), AssetError::AssetNotFound(asset));
-        let sub_id = sub_id.unwrap();
-        assert(
-            _mint(
-                storage
-                    .total_assets,
-                storage
-                    .total_supply,
-                recipient,
-                sub_id,
-                amount,
-            ) == asset,
-        );
-        if storage.restricted_mint.get(asset).read() {
-            only_owner();
+        mint_asset(recipient, asset, amount);
+    }
+
+    #[storage(read, write)]
+    fn mint_batch(mints: Vec<(Identity, AssetId, u64)>) {
+        require(mints.len() > 0, ValueError::ZeroValue);
+        for mint in mints.iter() {
+            let (recipient, asset, amount) = mint;
+            mint_asset(recipient, asset, amount);
+        }
+    }
+
+    #[storage(read, write)]
+    fn pause(asset: Option<AssetId>) {
+        only_owner();
+        match asset {
+            Some(asset) => {
+                require(
+                    storage
+                        .sub_id
+                        .get(asset)
+                        .try_read()
+                        .is_some(),
+                    AssetError::AssetNotFound(asset),
+                );
+                storage.asset_paused.insert(asset, true);
+            }
+            None => storage.paused.write(true),
+        }
+        let sender = msg_sender().unwrap();
+        log(Paused { asset, sender });
+    }
+
+    #[storage(read, write)]
+    fn unpause(asset: Option<AssetId>) {
+        only_owner();
+        match asset {
+            Some(asset) => {
+                require(
+                    storage
+                        .sub_id
+                        .get(asset)
+                        .try_read()
+                        .is_some(),
+                    AssetError::AssetNotFound(asset),
+                );
+                storage.asset_paused.insert(asset, false);
+            }
+            None => storage.paused.write(false),
+        }
+        let sender = msg_sender().unwrap();
+        log(Unpaused { asset, sender });
+    }
+
+    #[storage(read)]
+    fn is_paused(asset: Option<AssetId>) -> bool {
+        let paused = storage.paused.read();
+        match asset {
+            Some(asset) => {
+                paused || storage.asset_paused.get(asset).try_read().unwrap_or(false)
+            },
+            None => paused,
         }
-        let minter = msg_sender().unwrap();
-        log(AssetMinted {
-            recipient,
-            asset,
-            amount,
-            minter,
-        });
     }
 
     #[storage(read)]
@@ -199,3 +259,92 @@ impl MultiAsset for Contract {
         storage.restricted_mint.get(asset).try_read()
     }
 }
+
+#[storage(read, write)]
+fn create_asset(
+    name: String,
+    symbol: String,
+    decimals: u8,
+    restricted_mint: bool,
+) -> AssetId {
+    only_owner();
+    require(!storage.paused.read(), PauseError::Paused);
+    let sub_id = sha256((ContractId::this(), symbol));
+    let asset = AssetId::new(ContractId::this(), sub_id);
+    require(
+        name
+            .as_bytes()
+            .len() > 0 && symbol
+            .as_bytes()
+            .len() > 0,
+        ValueError::ZeroStringLength,
+    );
+    require(decimals <= 12, ValueError::BadIntValue(decimals));
+    require(
+        storage
+            .sub_id
+            .get(asset)
+            .try_read()
+            .is_none(),
+        AssetError::AssetAlreadyExists(asset),
+    );
+    // Log the SRC-20 SetNameEvent, SetSymbolEvent and SetDecimalsEvent
+    _set_name(storage.name, asset, name);
+    _set_symbol(storage.symbol, asset, symbol);
+    _set_decimals(storage.decimals, asset, decimals);
+
+    storage.total_assets.write(storage.total_assets.read() + 1);
+    storage.total_supply.insert(asset, 0);
+    storage.sub_id.insert(asset, sub_id);
+    storage.asset.insert(sha256(symbol), asset);
+    storage.restricted_mint.insert(asset, restricted_mint);
+
+    let creator = msg_sender().unwrap();
+    log(AssetNew {
+        asset,
+        name,
+        symbol,
+        decimals,
+        creator,
+    });
+    asset
+}
+
+#[storage(read, write)]
+fn mint_asset(recipient: Identity, asset: AssetId, amount: u64) {
+    let sub_id = storage.sub_id.get(asset).try_read();
+    require(amount > 0, ValueError::ZeroValue);
+    require(sub_id.is_some(), AssetError::AssetNotFound(asset));
+    require(!storage.paused.read(), PauseError::Paused);
+    require(
+        !storage
+            .asset_paused
+            .get(asset)
+            .try_read()
+            .unwrap_or(false),
+        PauseError::AssetPaused(asset),
+    );
+    let sub_id = sub_id.unwrap();
+    // Logs the SRC-20 TotalSupplyEvent
+    assert(
+        _mint(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            recipient,
+            sub_id,
+            amount,
+        ) == asset,
+    );
+    if storage.restricted_mint.get(asset).read() {
+        only_owner();
+    }
+    let minter = msg_sender().unwrap();
+    log(AssetMinted {
+        recipient,
+        asset,
+        amount,
+        minter,
+    });
+}
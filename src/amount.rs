@@ -0,0 +1,102 @@
+/// Formats an amount in base units as a decimal, e.g. 150000000 with 8 decimals as `1.5`
+pub fn format_amount(amount: u64, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+/// Parses a decimal amount such as `1.5` into base units of an asset with `decimals`.
+/// Fails if the amount has more fractional digits than `decimals` or overflows `u64`.
+pub fn parse_amount(amount: &str, decimals: u8) -> anyhow::Result<u64> {
+    let (integer, fraction) = match amount.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (amount, ""),
+    };
+    if integer.is_empty() && fraction.is_empty()
+        || !integer.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        anyhow::bail!("Invalid amount {:?}", amount);
+    }
+
+    // Trailing zeros never lose precision
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        anyhow::bail!(
+            "Amount {} has more than {} decimal places",
+            amount,
+            decimals
+        );
+    }
+
+    let digits = format!(
+        "{}{:0<width$}",
+        integer,
+        fraction,
+        width = decimals as usize
+    );
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits
+        .parse::<u64>()
+        .map_err(|_| anyhow::anyhow!("Amount {} overflows u64 base units", amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_amount_with_decimals() {
+        assert_eq!(parse_amount("1.5", 8).unwrap(), 150_000_000);
+        assert_eq!(parse_amount("1", 8).unwrap(), 100_000_000);
+        assert_eq!(parse_amount(".5", 8).unwrap(), 50_000_000);
+        assert_eq!(parse_amount("0.00000001", 8).unwrap(), 1);
+        assert_eq!(parse_amount("0", 8).unwrap(), 0);
+        assert_eq!(parse_amount("42", 0).unwrap(), 42);
+
+        // Trailing zeros beyond the decimals lose no precision
+        assert_eq!(parse_amount("1.500000000", 8).unwrap(), 150_000_000);
+        assert_eq!(parse_amount("18446744073709551615", 0).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn format_amount_with_decimals() {
+        assert_eq!(format_amount(150_000_000, 8), "1.5");
+        assert_eq!(format_amount(100_000_000, 8), "1");
+        assert_eq!(format_amount(1, 8), "0.00000001");
+        assert_eq!(format_amount(0, 8), "0");
+        assert_eq!(format_amount(42, 0), "42");
+
+        for amount in [0, 1, 150_000_000, 123_456_789_012, u64::MAX] {
+            assert_eq!(parse_amount(&format_amount(amount, 9), 9).unwrap(), amount);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "more than 8 decimal places")]
+    fn parse_amount_precision_loss() {
+        parse_amount("0.000000001", 8).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64")]
+    fn parse_amount_overflow() {
+        parse_amount("184467440737.09551616", 8).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid amount")]
+    fn parse_invalid_amount() {
+        parse_amount("1.5.0", 8).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "indexer")]
+use crate::commands::indexer::cli::IndexerCommands;
 use crate::commands::{core::cli::CoreCommands, info::cli::InfoCommands};
 use clap::{Args, Parser, Subcommand};
 
@@ -10,13 +12,18 @@ pub(crate) struct Cli {
 
 #[derive(Clone, Subcommand)]
 pub(crate) enum Command {
-    ///
+    /// Contract calls that change state
     #[clap(short_flag = 'C')]
     Core(Core),
 
-    ///
+    /// Read-only contract queries
     #[clap(short_flag = 'I')]
     Info(Info),
+
+    /// Local SQLite index of contract events
+    #[cfg(feature = "indexer")]
+    #[clap(short_flag = 'X')]
+    Indexer(Indexer),
 }
 
 #[derive(Args, Clone)]
@@ -30,3 +37,10 @@ pub(crate) struct Info {
     #[clap(subcommand)]
     pub(crate) commands: InfoCommands,
 }
+
+#[cfg(feature = "indexer")]
+#[derive(Args, Clone)]
+pub(crate) struct Indexer {
+    #[clap(subcommand)]
+    pub(crate) commands: IndexerCommands,
+}
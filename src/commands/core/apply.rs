@@ -0,0 +1,237 @@
+use crate::commands::{
+    manifest::Manifest,
+    preflight::{check_not_paused, check_owner},
+    utils::{format_identity, setup, validate_contract_id, RetryArgs, TxPolicyArgs},
+};
+use clap::Args;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+use multiasset_sdk::{format_amount, MultiAssetContract};
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Creates missing assets and tops up allocations declared in a manifest")]
+pub(crate) struct ApplyCommand {
+    /// The TOML file declaring assets and their allocations
+    /// Ex. assets.toml
+    #[clap(long)]
+    pub(crate) manifest: PathBuf,
+
+    /// Only print the plan without sending transactions
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+enum Step {
+    AssetNew {
+        asset: AssetId,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        restricted_mint: bool,
+    },
+    Mint {
+        asset: AssetId,
+        symbol: String,
+        decimals: u8,
+        recipient: Identity,
+        amount: u64,
+    },
+}
+
+impl ApplyCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let manifest = Manifest::load(&self.manifest)?;
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
+        let provider = wallet.try_provider()?;
+
+        let mut steps = vec![];
+        for declared in manifest.assets.iter() {
+            let asset = contract.asset_id(&declared.symbol);
+            let exists = match contract.asset(&declared.symbol).await?.value {
+                Some(existing) if existing != asset => anyhow::bail!(
+                    "Asset {} exists with unexpected id 0x{}",
+                    declared.symbol,
+                    existing
+                ),
+                Some(_) => true,
+                None => false,
+            };
+            if !exists {
+                steps.push(Step::AssetNew {
+                    asset,
+                    name: declared.name.clone(),
+                    symbol: declared.symbol.clone(),
+                    decimals: declared.decimals,
+                    restricted_mint: declared.restricted_mint,
+                });
+            }
+
+            for allocation in declared.allocations.iter() {
+                let recipient = allocation.recipient()?;
+                let balance = match (exists, recipient) {
+                    (false, _) => 0,
+                    (true, Identity::Address(address)) => {
+                        provider.get_asset_balance(&address.into(), asset).await?
+                    }
+                    (true, Identity::ContractId(contract_id)) => {
+                        provider
+                            .get_contract_asset_balance(&contract_id.into(), asset)
+                            .await?
+                    }
+                };
+                if balance < allocation.amount {
+                    steps.push(Step::Mint {
+                        asset,
+                        symbol: declared.symbol.clone(),
+                        decimals: declared.decimals,
+                        recipient,
+                        amount: allocation.amount - balance,
+                    });
+                }
+            }
+        }
+
+        if steps.is_empty() {
+            println!("\nNothing to apply, the contract matches the manifest");
+            return Ok(());
+        }
+
+        println!("\nPlan:");
+        for step in steps.iter() {
+            match step {
+                Step::AssetNew {
+                    asset,
+                    name,
+                    symbol,
+                    decimals,
+                    restricted_mint,
+                } => println!(
+                    "  create asset {} 0x{} name: {} decimals: {} restricted mint: {}",
+                    symbol, asset, name, decimals, restricted_mint
+                ),
+                Step::Mint {
+                    symbol,
+                    decimals,
+                    recipient,
+                    amount,
+                    ..
+                } => println!(
+                    "  mint {} {} to: {}",
+                    format_amount(*amount, *decimals),
+                    symbol,
+                    format_identity(recipient)
+                ),
+            }
+        }
+
+        if self.dry_run {
+            return Ok(());
+        }
+
+        if steps
+            .iter()
+            .any(|step| matches!(step, Step::AssetNew { .. }))
+        {
+            check_owner(&contract, &wallet).await?;
+        }
+        check_not_paused(&contract, None).await?;
+
+        // Initial balance prior to contract calls - used to calculate contract interaction cost
+        let balance = wallet.get_asset_balance(provider.base_asset_id()).await?;
+
+        // Create every missing asset in one transaction, so none is created if any fails
+        let creates: Vec<(&str, &str, u8, bool, AssetId)> = steps
+            .iter()
+            .filter_map(|step| match step {
+                Step::AssetNew {
+                    asset,
+                    name,
+                    symbol,
+                    decimals,
+                    restricted_mint,
+                } => Some((
+                    name.as_str(),
+                    symbol.as_str(),
+                    *decimals,
+                    *restricted_mint,
+                    *asset,
+                )),
+                Step::Mint { .. } => None,
+            })
+            .collect();
+        if !creates.is_empty() {
+            let assets: Vec<(&str, &str, u8, bool)> = creates
+                .iter()
+                .map(|(name, symbol, decimals, restricted_mint, _)| {
+                    (*name, *symbol, *decimals, *restricted_mint)
+                })
+                .collect();
+            let created = contract.asset_new_batch(&assets).await?.value;
+            for ((_, symbol, _, _, asset), created) in creates.iter().zip(created.iter()) {
+                if created != asset {
+                    anyhow::bail!(
+                        "Asset {} created with id 0x{}, expected 0x{}",
+                        symbol,
+                        created,
+                        asset
+                    );
+                }
+                println!("\nA new asset created with id: 0x{}", asset);
+            }
+        }
+
+        for step in steps.iter() {
+            if let Step::Mint {
+                asset,
+                symbol,
+                decimals,
+                recipient,
+                amount,
+            } = step
+            {
+                contract.mint(*recipient, asset, *amount).await?;
+                println!(
+                    "\nAn asset 0x{} amount minted {} {} to: {}",
+                    asset,
+                    format_amount(*amount, *decimals),
+                    symbol,
+                    format_identity(recipient)
+                );
+            }
+        }
+
+        // Balance post-apply
+        let new_balance = wallet.get_asset_balance(provider.base_asset_id()).await?;
+
+        println!("Transaction cost: {}", balance - new_balance);
+        println!("Signer: 0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
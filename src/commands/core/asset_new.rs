@@ -1,4 +1,7 @@
-use crate::commands::utils::{setup, validate_contract_id};
+use crate::commands::{
+    preflight::check_asset_new,
+    utils::{setup, validate_contract_id, RetryArgs, TxPolicyArgs},
+};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use multiasset_sdk::MultiAssetContract;
@@ -27,9 +30,16 @@ pub(crate) struct AssetNewCommand {
     pub(crate) restricted_mint: bool,
 
     /// The contract id of the market
+    /// Or the path to its deployment record
     #[clap(long)]
     pub(crate) contract_id: String,
 
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
     /// The URL to query
     /// Ex. testnet.fuel.network
     #[clap(long)]
@@ -43,11 +53,24 @@ impl AssetNewCommand {
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         // Connect to the deployed contract via the rpc
-        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
+
+        check_asset_new(
+            &contract,
+            &wallet,
+            &self.name,
+            &self.symbol,
+            self.decimals,
+            self.restricted_mint,
+        )
+        .await?;
 
         // Create a new asset
         let asset = contract
@@ -62,7 +85,7 @@ impl AssetNewCommand {
 
         // Balance post-deployment
         let new_balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         println!("\nA new asset created with id: 0x{}", asset);
@@ -1,11 +1,16 @@
 use crate::commands::core::{
-    asset_new::AssetNewCommand, deploy::DeployCommand, mint::MintCommand,
-    mint_many::MintManyCommand,
+    apply::ApplyCommand, asset_new::AssetNewCommand, deploy::DeployCommand,
+    distribute::DistributeCommand, mint::MintCommand, mint_batch::MintBatchCommand,
+    mint_many::MintManyCommand, pause::PauseCommand, set_metadata::SetMetadataCommand,
 };
 use clap::Subcommand;
 
 #[derive(Clone, Subcommand)]
 pub(crate) enum CoreCommands {
+    /// Create missing assets and top up allocations from a manifest
+    #[clap(short_flag = 'L')]
+    Apply(ApplyCommand),
+
     /// Create a new asset
     #[clap(short_flag = 'A')]
     AssetNew(AssetNewCommand),
@@ -14,11 +19,31 @@ pub(crate) enum CoreCommands {
     #[clap(short_flag = 'D')]
     Deploy(DeployCommand),
 
+    /// Distributes the signer's existing asset balances to recipients
+    #[clap(short_flag = 'T')]
+    Distribute(DistributeCommand),
+
     /// Mints an asset amount to recipient
     #[clap(short_flag = 'M')]
     Mint(MintCommand),
 
+    /// Mints amounts of several assets to recipients in one transaction
+    #[clap(short_flag = 'B')]
+    MintBatch(MintBatchCommand),
+
     /// Mints an asset amount to recipients
     #[clap(short_flag = 'N')]
     MintMany(MintManyCommand),
+
+    /// Pauses minting of an asset or the whole contract
+    #[clap(short_flag = 'P')]
+    Pause(PauseCommand),
+
+    /// Sets an asset SRC-7 metadata entry
+    #[clap(short_flag = 'S')]
+    SetMetadata(SetMetadataCommand),
+
+    /// Unpauses minting of an asset or the whole contract
+    #[clap(short_flag = 'U')]
+    Unpause(PauseCommand),
 }
@@ -1,11 +1,27 @@
-use crate::commands::utils::setup;
+use crate::commands::utils::{setup, ArtifactArgs, SaltArgs, TxPolicyArgs};
 use clap::Args;
 use fuels::accounts::ViewOnlyAccount;
 use multiasset_sdk::MultiAssetContract;
+use rand::Rng;
+use std::path::PathBuf;
 
 #[derive(Args, Clone)]
 #[command(about = "Deploys the market to a network")]
 pub(crate) struct DeployCommand {
+    /// The file to write the deployment record to
+    /// Must not exist yet
+    #[clap(long, default_value = "deployment.json")]
+    pub(crate) record: PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) salt: SaltArgs,
+
+    #[clap(flatten)]
+    pub(crate) artifacts: ArtifactArgs,
+
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
     /// The URL to query
     /// Ex. testnet.fuel.network
     #[clap(long)]
@@ -14,24 +30,45 @@ pub(crate) struct DeployCommand {
 
 impl DeployCommand {
     pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        if self.record.exists() {
+            anyhow::bail!(
+                "Deployment record {} already exists, pass another --record",
+                self.record.display()
+            );
+        }
+
+        let salt = match self.salt.salt()? {
+            Some(salt) => salt,
+            None => rand::thread_rng().gen::<[u8; 32]>().into(),
+        };
+
         let wallet = setup(&self.rpc).await?;
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         // Deploy the contract
-        let contract = MultiAssetContract::deploy(&wallet).await?;
+        let config = self
+            .artifacts
+            .deploy_config()
+            .with_salt(salt)
+            .with_tx_policies(self.tx_policies.tx_policies());
+        let (contract, deployment) =
+            MultiAssetContract::deploy_with_config(&wallet, &config).await?;
+        deployment.save(&self.record)?;
 
         // Balance post-deployment
         let new_balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         println!("\nMultiAsset contract deployed to: 0x{}", contract.id());
         println!("Deployment cost: {}", balance - new_balance);
         println!("Deployer: 0x{}", wallet.address().hash());
+        println!("Salt: 0x{}", salt);
+        println!("Deployment record: {}", self.record.display());
 
         Ok(())
     }
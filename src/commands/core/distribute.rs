@@ -0,0 +1,110 @@
+use crate::commands::{
+    recipients::RecipientRow,
+    utils::{format_identity, setup, validate_contract_id, AssetUnits, TxPolicyArgs},
+};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, types::AssetId};
+use multiasset_sdk::MultiAssetContract;
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Distributes the signer's existing asset balances to recipients")]
+pub(crate) struct DistributeCommand {
+    /// The CSV file of transfers with a `recipient_id,recipient_type,asset,amount` header
+    #[clap(long)]
+    pub(crate) file: PathBuf,
+
+    /// Read the amounts as integers of base units instead of whole tokens
+    #[clap(long)]
+    pub(crate) base_units: bool,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl DistributeCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let rows = RecipientRow::load(&self.file)?;
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Initial balance prior to transfers - used to calculate the transaction cost
+        let balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies());
+
+        // The units of every asset, fetched once in the order the assets first appear
+        let base_asset_id = *wallet.provider().unwrap().base_asset_id();
+        let mut assets: Vec<(AssetId, AssetUnits)> = vec![];
+        let mut transfers = vec![];
+        for row in rows.iter() {
+            let asset = row.asset()?;
+            let index = match assets.iter().position(|(known, _)| *known == asset) {
+                Some(index) => index,
+                None => {
+                    let units = if asset == base_asset_id {
+                        AssetUnits::base()
+                    } else {
+                        AssetUnits::fetch(&contract, &asset).await?
+                    };
+                    assets.push((asset, units));
+                    assets.len() - 1
+                }
+            };
+            let amount = assets[index].1.parse(&row.amount, self.base_units)?;
+            transfers.push((row.recipient()?, asset, amount));
+        }
+
+        // Report every transaction as it commits, so those sent before a failure are known
+        contract
+            .distribute_assets(&transfers, |tx_id, paid| {
+                for (recipient, asset, amount) in paid.iter() {
+                    let units = assets
+                        .iter()
+                        .find_map(|(known, units)| (known == asset).then_some(units))
+                        .expect("every distributed asset has units");
+                    println!(
+                        "\nAn asset 0x{} amount distributed {} to: {}",
+                        asset,
+                        units.format(*amount),
+                        format_identity(recipient)
+                    );
+                }
+                println!("Transaction id: 0x{}", tx_id);
+            })
+            .await?;
+
+        // Balance post-distribution, without the distributed base asset
+        let new_balance = wallet.get_asset_balance(&base_asset_id).await?;
+        let distributed: u64 = transfers
+            .iter()
+            .filter(|(_, asset, _)| *asset == base_asset_id)
+            .map(|(.., amount)| amount)
+            .sum();
+
+        println!(
+            "Transaction cost: {}",
+            balance
+                .saturating_sub(new_balance)
+                .saturating_sub(distributed)
+        );
+        println!("Sender: 0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
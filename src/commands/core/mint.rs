@@ -1,4 +1,7 @@
-use crate::commands::utils::{setup, validate_contract_id, IdentityType};
+use crate::commands::{
+    preflight::check_mint,
+    utils::{setup, validate_contract_id, AssetUnits, IdentityType, RetryArgs, TxPolicyArgs},
+};
 use clap::Args;
 use fuels::{
     accounts::ViewOnlyAccount,
@@ -22,15 +25,27 @@ pub(crate) struct MintCommand {
     #[clap(long)]
     pub(crate) asset: String,
 
-    /// The amount to mint
-    /// Ex. 10000000
+    /// The amount to mint in whole tokens, optionally followed by the asset symbol
+    /// Ex. 1.5 or 1.5BTC
+    #[clap(long)]
+    pub(crate) amount: String,
+
+    /// Read the amount as an integer of base units instead of whole tokens
+    /// Ex. --amount 150000000 --base-units
     #[clap(long)]
-    pub(crate) amount: u64,
+    pub(crate) base_units: bool,
 
     /// The contract id of the market
+    /// Or the path to its deployment record
     #[clap(long)]
     pub(crate) contract_id: String,
 
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
     /// The URL to query
     /// Ex. testnet.fuel.network
     #[clap(long)]
@@ -44,7 +59,7 @@ impl MintCommand {
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         if self.asset.len() as u64 != 66 {
@@ -54,7 +69,23 @@ impl MintCommand {
         let asset_id = AssetId::from_str(&self.asset).expect("Invalid asset");
 
         // Connect to the deployed contract via the rpc
-        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
+
+        let units = AssetUnits::fetch(&contract, &asset_id).await?;
+        let amount = units.parse(&self.amount, self.base_units)?;
+
+        let recipient = match self.recipient_type {
+            IdentityType::Address => {
+                Identity::Address(Address::from_str(&self.recipient_id).expect("Invalid address"))
+            }
+            IdentityType::Contract => Identity::ContractId(
+                ContractId::from_str(&self.recipient_id).expect("Invalid contract id"),
+            ),
+        };
+        check_mint(&contract, &wallet, recipient, &asset_id, amount, 1).await?;
 
         // Mint asset amount
         let minted = match self.recipient_type {
@@ -62,14 +93,14 @@ impl MintCommand {
                 let address = Address::from_str(&self.recipient_id).expect("Invalid address");
                 let asset_balance = wallet
                     .try_provider()?
-                    .get_asset_balance(&address.into(), asset_id.clone())
+                    .get_asset_balance(&address.into(), asset_id)
                     .await?;
                 contract
-                    .mint(Identity::Address(address), &asset_id, self.amount)
+                    .mint(Identity::Address(address), &asset_id, amount)
                     .await?;
                 let new_asset_balance = wallet
                     .try_provider()?
-                    .get_asset_balance(&address.into(), asset_id.clone())
+                    .get_asset_balance(&address.into(), asset_id)
                     .await?;
                 new_asset_balance - asset_balance
             }
@@ -78,7 +109,7 @@ impl MintCommand {
                     ContractId::from_str(&self.recipient_id).expect("Invalid contract id");
                 //let asset_balance = wallet.try_provider()?.get_asset_balance(&address.into(), asset_id.clone()).await?;
                 contract
-                    .mint(Identity::ContractId(address), &asset_id, self.amount)
+                    .mint(Identity::ContractId(address), &asset_id, amount)
                     .await?;
                 //let new_asset_balance = wallet.try_provider()?.get_asset_balance(&address.into(), asset_id.clone()).await?;
                 0 // Todo new_asset_balance - asset_balance
@@ -87,12 +118,14 @@ impl MintCommand {
 
         // Balance post-deployment
         let new_balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         println!(
             "\nAn asset {} amount minted {} to: {}",
-            self.asset, minted, self.recipient_id
+            self.asset,
+            units.format(minted),
+            self.recipient_id
         );
         println!("Transaction cost: {}", balance - new_balance);
         println!("Minter: 0x{}", wallet.address().hash());
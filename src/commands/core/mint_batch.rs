@@ -0,0 +1,112 @@
+use crate::commands::{
+    preflight::check_mint_batch,
+    recipients::RecipientRow,
+    utils::{format_identity, setup, validate_contract_id, AssetUnits, RetryArgs, TxPolicyArgs},
+};
+use clap::Args;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+use multiasset_sdk::MultiAssetContract;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    path::PathBuf,
+};
+
+#[derive(Args, Clone)]
+#[command(about = "Mints amounts of several assets to recipients in one transaction")]
+pub(crate) struct MintBatchCommand {
+    /// The CSV file of mints with a `recipient_id,recipient_type,asset,amount` header
+    #[clap(long)]
+    pub(crate) file: PathBuf,
+
+    /// Read the amounts as integers of base units instead of whole tokens
+    #[clap(long)]
+    pub(crate) base_units: bool,
+
+    /// Only estimate the gas and fee of the batch and check the balance covers it
+    #[clap(long)]
+    pub(crate) estimate: bool,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl MintBatchCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let rows = RecipientRow::load(&self.file)?;
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
+
+        let mut units: HashMap<AssetId, AssetUnits> = HashMap::new();
+        let mut mints: Vec<(Identity, AssetId, u64)> = vec![];
+        for row in rows.iter() {
+            let asset = row.asset()?;
+            let asset_units = match units.entry(asset) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(AssetUnits::fetch(&contract, &asset).await?),
+            };
+            let amount = asset_units.parse(&row.amount, self.base_units)?;
+            mints.push((row.recipient()?, asset, amount));
+        }
+
+        // Check every entry can succeed and the batch is affordable before sending it
+        let estimate = check_mint_batch(&contract, &wallet, &mints).await?;
+        println!(
+            "\nEstimated cost of {} mints in one transaction: {} gas, fee: {}",
+            mints.len(),
+            estimate.gas_used,
+            estimate.fee
+        );
+        if self.estimate {
+            println!("Balance: {}", balance);
+            return Ok(());
+        }
+
+        let response = contract.mint_batch(&mints).await?;
+        for (recipient, asset, amount) in mints.iter() {
+            println!(
+                "\nAn asset 0x{} amount minted {} to: {}",
+                asset,
+                units[asset].format(*amount),
+                format_identity(recipient)
+            );
+        }
+
+        // Balance post-deployment
+        let new_balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!("Transaction id: 0x{}", response.tx_id.unwrap_or_default());
+        println!("Transaction cost: {}", balance - new_balance);
+        println!("Minter: 0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
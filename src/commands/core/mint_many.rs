@@ -1,11 +1,15 @@
-use crate::commands::utils::{setup, validate_contract_id, IdentityType};
+use crate::commands::{
+    preflight::check_mint,
+    utils::{setup, validate_contract_id, AssetUnits, IdentityType, RetryArgs, TxPolicyArgs},
+};
 use clap::Args;
 use fuels::{
     accounts::ViewOnlyAccount,
     types::{Address, AssetId, ContractId, Identity},
 };
 use multiasset_sdk::MultiAssetContract;
-use std::str::FromStr;
+use std::{str::FromStr, time::Instant};
+use tokio::task::JoinSet;
 
 #[derive(Args, Clone)]
 #[command(about = "Mints an asset amount to recipients")]
@@ -22,15 +26,37 @@ pub(crate) struct MintManyCommand {
     #[clap(long)]
     pub(crate) asset: String,
 
-    /// The amount to mint
-    /// Ex. 10000000
+    /// The amount to mint in whole tokens, optionally followed by the asset symbol
+    /// Ex. 1.5 or 1.5BTC
+    #[clap(long)]
+    pub(crate) amount: String,
+
+    /// Read the amount as an integer of base units instead of whole tokens
+    /// Ex. --amount 150000000 --base-units
+    #[clap(long)]
+    pub(crate) base_units: bool,
+
+    /// Only estimate the gas and fee of the whole batch and check the balance covers it
+    #[clap(long)]
+    pub(crate) estimate: bool,
+
+    /// Send up to this many mints at once instead of one after another.
+    /// The base asset is split into one coin per recipient first, so the mints never
+    /// spend the same coin.
     #[clap(long)]
-    pub(crate) amount: u64,
+    pub(crate) concurrency: Option<usize>,
 
     /// The contract id of the market
+    /// Or the path to its deployment record
     #[clap(long)]
     pub(crate) contract_id: String,
 
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
     /// The URL to query
     /// Ex. testnet.fuel.network
     #[clap(long)]
@@ -44,7 +70,7 @@ impl MintManyCommand {
 
         // Initial balance prior to contract call - used to calculate contract interaction cost
         let balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         if self.asset.len() as u64 != 66 {
@@ -54,39 +80,131 @@ impl MintManyCommand {
         let asset_id = AssetId::from_str(&self.asset).expect("Invalid asset");
 
         // Connect to the deployed contract via the rpc
-        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
 
-        // Mint asset amount
-        match self.recipient_type {
-            IdentityType::Address => {
-                for recipient_id in &self.recipient_id {
-                    let address = Address::from_str(&recipient_id).expect("Invalid address");
-                    contract
-                        .mint(Identity::Address(address), &asset_id, self.amount)
-                        .await?;
-                    println!(
-                        "\nAn asset {} amount minted {} to: {}",
-                        self.asset, self.amount, recipient_id
-                    );
+        let units = AssetUnits::fetch(&contract, &asset_id).await?;
+        let amount = units.parse(&self.amount, self.base_units)?;
+
+        let recipients = self
+            .recipient_id
+            .iter()
+            .map(|recipient_id| match self.recipient_type {
+                IdentityType::Address => {
+                    Identity::Address(Address::from_str(recipient_id).expect("Invalid address"))
                 }
+                IdentityType::Contract => Identity::ContractId(
+                    ContractId::from_str(recipient_id).expect("Invalid contract id"),
+                ),
+            })
+            .collect::<Vec<Identity>>();
+        let first = match recipients.first() {
+            Some(first) => *first,
+            None => anyhow::bail!("No recipients given"),
+        };
+
+        // Check the whole batch can succeed and is affordable before sending the first mint
+        let estimate = check_mint(
+            &contract,
+            &wallet,
+            first,
+            &asset_id,
+            amount,
+            recipients.len() as u64,
+        )
+        .await?;
+        println!(
+            "\nEstimated cost of {} mints: {} gas, fee: {}",
+            recipients.len(),
+            estimate.gas_used,
+            estimate.fee
+        );
+        if self.estimate {
+            println!("Balance: {}", balance);
+            return Ok(());
+        }
+
+        if let Some(concurrency) = self.concurrency {
+            if concurrency == 0 {
+                anyhow::bail!("The concurrency must be positive");
             }
-            IdentityType::Contract => {
-                for recipient_id in &self.recipient_id {
-                    let address = ContractId::from_str(&recipient_id).expect("Invalid contract id");
-                    contract
-                        .mint(Identity::ContractId(address), &asset_id, self.amount)
-                        .await?;
-                    println!(
-                        "\nAn asset {} amount minted {} to: {}",
-                        self.asset, self.amount, recipient_id
-                    );
+
+            // Every coin covers twice the estimated fee of one mint in case the price rises
+            let fee = contract.estimate_mint(first, &asset_id, amount).await?.fee;
+            let coins = contract
+                .split_coins(recipients.len(), fee.saturating_mul(2).max(1))
+                .await?;
+            if coins.len() < recipients.len() {
+                anyhow::bail!(
+                    "Split {} coins for {} recipients",
+                    coins.len(),
+                    recipients.len()
+                );
+            }
+            println!("\nSplit {} coins for the mints", coins.len());
+
+            let started = Instant::now();
+            let mut mints = JoinSet::new();
+            let mut failures = vec![];
+            let mut minted = 0;
+            for ((recipient, recipient_id), coin) in recipients
+                .iter()
+                .copied()
+                .zip(self.recipient_id.iter().cloned())
+                .zip(coins)
+            {
+                // Wait for a mint to finish before exceeding the concurrency
+                if mints.len() >= concurrency {
+                    if let Some(result) = mints.join_next().await {
+                        self.report(result?, &units, amount, &mut minted, &mut failures);
+                    }
                 }
+                let contract = contract.with_account(&wallet);
+                mints.spawn(async move {
+                    let result = contract
+                        .mint_with_coin(recipient, &asset_id, amount, coin)
+                        .await;
+                    (recipient_id, result.map(|_| ()))
+                });
             }
-        };
+            while let Some(result) = mints.join_next().await {
+                self.report(result?, &units, amount, &mut minted, &mut failures);
+            }
+
+            let elapsed = started.elapsed();
+            println!(
+                "\nMinted {} of {} in {:.2}s ({:.2} mints/s)",
+                minted,
+                recipients.len(),
+                elapsed.as_secs_f64(),
+                minted as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+            );
+            for (recipient_id, error) in &failures {
+                println!("Failed to mint to: {} error: {}", recipient_id, error);
+            }
+            if !failures.is_empty() {
+                anyhow::bail!("{} mints failed", failures.len());
+            }
+            println!("Minter: 0x{}", wallet.address().hash());
+            return Ok(());
+        }
+
+        // Mint asset amount
+        for (recipient, recipient_id) in recipients.iter().zip(self.recipient_id.iter()) {
+            contract.mint(*recipient, &asset_id, amount).await?;
+            println!(
+                "\nAn asset {} amount minted {} to: {}",
+                self.asset,
+                units.format(amount),
+                recipient_id
+            );
+        }
 
         // Balance post-deployment
         let new_balance = wallet
-            .get_asset_balance(&wallet.provider().unwrap().base_asset_id())
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
             .await?;
 
         println!("Transaction cost: {}", balance - new_balance);
@@ -94,4 +212,26 @@ impl MintManyCommand {
 
         Ok(())
     }
+
+    fn report(
+        &self,
+        (recipient_id, result): (String, anyhow::Result<()>),
+        units: &AssetUnits,
+        amount: u64,
+        minted: &mut usize,
+        failures: &mut Vec<(String, anyhow::Error)>,
+    ) {
+        match result {
+            Ok(()) => {
+                *minted += 1;
+                println!(
+                    "\nAn asset {} amount minted {} to: {}",
+                    self.asset,
+                    units.format(amount),
+                    recipient_id
+                );
+            }
+            Err(error) => failures.push((recipient_id, error)),
+        }
+    }
 }
@@ -1,5 +1,10 @@
+pub(crate) mod apply;
 pub(crate) mod asset_new;
 pub(crate) mod cli;
 pub(crate) mod deploy;
+pub(crate) mod distribute;
 pub(crate) mod mint;
+pub(crate) mod mint_batch;
 pub(crate) mod mint_many;
+pub(crate) mod pause;
+pub(crate) mod set_metadata;
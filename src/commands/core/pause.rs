@@ -0,0 +1,90 @@
+use crate::commands::{
+    preflight::{check_asset, check_owner},
+    utils::{setup, validate_contract_id, RetryArgs, TxPolicyArgs},
+};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, types::AssetId};
+use multiasset_sdk::MultiAssetContract;
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Pauses or unpauses minting of an asset or the whole contract")]
+pub(crate) struct PauseCommand {
+    /// The asset id to pause or unpause
+    /// Applies to the whole contract if omitted
+    #[clap(long)]
+    pub(crate) asset: Option<String>,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl PauseCommand {
+    /// Pauses, or unpauses if `unpause` is set, as `core pause` and `core unpause` share arguments
+    pub(crate) async fn run(&self, unpause: bool) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        let asset_id = match &self.asset {
+            Some(asset) => {
+                if asset.len() as u64 != 66 {
+                    anyhow::bail!("Invalid fuel asset length");
+                }
+                Some(AssetId::from_str(asset).expect("Invalid asset"))
+            }
+            None => None,
+        };
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
+
+        check_owner(&contract, &wallet).await?;
+        if let Some(asset_id) = &asset_id {
+            check_asset(&contract, asset_id).await?;
+        }
+
+        // Pause or unpause the asset or the contract
+        let state = if unpause {
+            contract.unpause(asset_id).await?;
+            "unpaused"
+        } else {
+            contract.pause(asset_id).await?;
+            "paused"
+        };
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        match &self.asset {
+            Some(asset) => println!("\nAn asset {} is {}", asset, state),
+            None => println!("\nA contract 0x{} is {}", contract.id(), state),
+        }
+        println!("Transaction cost: {}", balance - new_balance);
+        println!("Owner: 0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::commands::{
+    preflight::{check_asset, check_owner},
+    utils::{setup, validate_contract_id, MetadataType, RetryArgs, TxPolicyArgs},
+};
+use clap::Args;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Bits256, Bytes},
+};
+use multiasset_sdk::{Metadata, MultiAssetContract};
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Sets an asset SRC-7 metadata entry")]
+pub(crate) struct SetMetadataCommand {
+    /// The asset id
+    #[clap(long)]
+    pub(crate) asset: String,
+
+    /// The metadata key
+    /// Ex. icon_url
+    #[clap(long)]
+    pub(crate) key: String,
+
+    /// The metadata value
+    /// Ex. https://example.com/btc.svg
+    #[clap(long)]
+    pub(crate) value: String,
+
+    /// The type of metadata value
+    #[clap(long, default_value = "string")]
+    pub(crate) value_type: MetadataType,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    #[clap(flatten)]
+    pub(crate) tx_policies: TxPolicyArgs,
+
+    #[clap(flatten)]
+    pub(crate) retry: RetryArgs,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl SetMetadataCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        if self.asset.len() as u64 != 66 {
+            anyhow::bail!("Invalid fuel asset length");
+        }
+
+        let asset_id = AssetId::from_str(&self.asset).expect("Invalid asset");
+
+        let metadata = match self.value_type {
+            MetadataType::B256 => Metadata::B256(Bits256::from_hex_str(&self.value)?),
+            MetadataType::Bytes => Metadata::Bytes(Bytes::from_hex_str(&self.value)?),
+            MetadataType::Int => Metadata::Int(self.value.parse()?),
+            MetadataType::String => Metadata::String(self.value.clone()),
+        };
+
+        // Initial balance prior to contract call - used to calculate contract interaction cost
+        let balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone())
+            .await
+            .with_tx_policies(self.tx_policies.tx_policies())
+            .with_retry_policy(self.retry.retry_policy());
+
+        check_owner(&contract, &wallet).await?;
+        check_asset(&contract, &asset_id).await?;
+
+        // Set the metadata entry
+        contract
+            .set_metadata(&asset_id, &self.key, metadata)
+            .await?;
+
+        // Balance post-call
+        let new_balance = wallet
+            .get_asset_balance(wallet.provider().unwrap().base_asset_id())
+            .await?;
+
+        println!(
+            "\nAn asset {} metadata {:?} set to: {}",
+            self.asset, self.key, self.value
+        );
+        println!("Transaction cost: {}", balance - new_balance);
+        println!("Owner: 0x{}", wallet.address().hash());
+
+        Ok(())
+    }
+}
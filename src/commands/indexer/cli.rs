@@ -0,0 +1,13 @@
+use crate::commands::indexer::{holders::HoldersCommand, run::RunCommand};
+use clap::Subcommand;
+
+#[derive(Clone, Subcommand)]
+pub(crate) enum IndexerCommands {
+    /// Query indexed holders of an asset
+    #[clap(short_flag = 'H')]
+    Holders(HoldersCommand),
+
+    /// Follow new blocks and index contract events
+    #[clap(short_flag = 'R')]
+    Run(RunCommand),
+}
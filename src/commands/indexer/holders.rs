@@ -0,0 +1,65 @@
+use crate::commands::utils::{format_identity, validate_contract_id, AssetUnits};
+use clap::Args;
+use fuels::types::AssetId;
+use multiasset_sdk::indexer::Indexer;
+use std::{path::PathBuf, str::FromStr};
+
+#[derive(Args, Clone)]
+#[command(about = "Query indexed holders of an asset without connecting to a node")]
+pub(crate) struct HoldersCommand {
+    /// The SQLite database file
+    /// Ex. multiasset.db
+    #[clap(long)]
+    pub(crate) db: PathBuf,
+
+    /// The asset id
+    #[clap(long)]
+    pub(crate) asset: String,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+}
+
+impl HoldersCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        if self.asset.len() as u64 != 66 {
+            anyhow::bail!("Invalid fuel asset length");
+        }
+
+        let asset_id = AssetId::from_str(&self.asset).expect("Invalid asset");
+
+        let indexer = Indexer::open(&self.db, contract_id)?;
+        let holders = indexer.holders(&asset_id)?;
+        // Assets created before the first indexed block have no decimals to format with
+        let units = indexer
+            .assets()?
+            .into_iter()
+            .find(|indexed| indexed.asset == asset_id)
+            .map(|indexed| AssetUnits {
+                decimals: indexed.decimals,
+                symbol: indexed.symbol,
+            });
+
+        println!(
+            "\nAn asset {} has {} holders as of block: {}",
+            self.asset,
+            holders.len(),
+            indexer
+                .cursor()?
+                .map_or(String::from("none"), |cursor| cursor.to_string())
+        );
+        for (recipient, total) in holders.iter() {
+            let total = match &units {
+                Some(units) => units.format(*total),
+                None => total.to_string(),
+            };
+            println!("{} minted: {}", format_identity(recipient), total);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod cli;
+pub(crate) mod holders;
+pub(crate) mod run;
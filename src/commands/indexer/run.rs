@@ -0,0 +1,70 @@
+use crate::commands::utils::{scan_from, setup, validate_contract_id};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use multiasset_sdk::{indexer::Indexer, MultiAssetContract};
+use std::{path::PathBuf, time::Duration};
+
+#[derive(Args, Clone)]
+#[command(about = "Follows new blocks and indexes contract events into a SQLite database")]
+pub(crate) struct RunCommand {
+    /// The SQLite database file
+    /// Ex. multiasset.db
+    #[clap(long)]
+    pub(crate) db: PathBuf,
+
+    /// The block height to start indexing from on the first run
+    /// Defaults to the block of the deployment record given as the contract id
+    #[clap(long)]
+    pub(crate) from: Option<u32>,
+
+    /// The seconds to wait between polls for new blocks
+    #[clap(long, default_value_t = 5)]
+    pub(crate) poll_interval: u64,
+
+    /// Stop once the latest block is indexed instead of following new blocks
+    #[clap(long)]
+    pub(crate) once: bool,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl RunCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+
+        let mut indexer = Indexer::open(&self.db, contract_id)?;
+        // Only the first run needs a start block, later runs resume after the cursor
+        let from = match indexer.cursor()? {
+            Some(cursor) => {
+                println!("\nResuming after block: {}", cursor);
+                cursor
+            }
+            None => scan_from(&self.contract_id, self.from)?,
+        };
+
+        loop {
+            let latest = wallet.try_provider()?.latest_block_height().await?;
+            if indexer.cursor()?.is_none_or(|cursor| cursor < latest) {
+                let cursor = indexer.sync(&contract, from, latest).await?;
+                println!("Indexed up to block: {}", cursor);
+            }
+
+            if self.once {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_secs(self.poll_interval)).await;
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use crate::commands::info::{
-    bech32_conv::Bech32ConvCommand, decimals::DecimalsCommand, name::NameCommand,
+    bech32_conv::Bech32ConvCommand, contract_id::ContractIdCommand, decimals::DecimalsCommand,
+    deployment::DeploymentCommand, diff::DiffCommand, history::HistoryCommand,
+    holders::HoldersCommand, metadata::MetadataCommand, name::NameCommand,
     restricted_mint::RestrictedMintCommand, symbol::SymbolCommand,
-    total_assets::TotalAssetsCommand, total_supply::TotalSupplyCommand,
+    total_assets::TotalAssetsCommand, total_supply::TotalSupplyCommand, tx::TxCommand,
+    watch::WatchCommand,
 };
 use clap::Subcommand;
 
@@ -15,10 +18,34 @@ pub(crate) enum InfoCommands {
     #[clap(short_flag = 'B')]
     Bech32Conv(Bech32ConvCommand),
 
+    /// Predict offline the contract id of a deployment with a salt
+    #[clap(short_flag = 'C')]
+    ContractId(ContractIdCommand),
+
     /// Query asset decimals information
     #[clap(short_flag = 'D')]
     Decimals(DecimalsCommand),
 
+    /// Verify a deployment record against its deployment transaction
+    #[clap(short_flag = 'E')]
+    Deployment(DeploymentCommand),
+
+    /// Compare assets declared in a manifest with the contract state
+    #[clap(short_flag = 'F')]
+    Diff(DiffCommand),
+
+    /// Query asset creation and mint history in a block range
+    #[clap(short_flag = 'H')]
+    History(HistoryCommand),
+
+    /// Query current balances of every recipient of an asset
+    #[clap(short_flag = 'O')]
+    Holders(HoldersCommand),
+
+    /// Query asset SRC-7 metadata information
+    #[clap(short_flag = 'M')]
+    Metadata(MetadataCommand),
+
     /// Query asset name information
     #[clap(short_flag = 'N')]
     Name(NameCommand),
@@ -34,4 +61,12 @@ pub(crate) enum InfoCommands {
     /// Query asset total supply information
     #[clap(short_flag = 'T')]
     TotalSupply(TotalSupplyCommand),
+
+    /// Query the status and contract events of a transaction
+    #[clap(short_flag = 'X')]
+    Tx(TxCommand),
+
+    /// Stream asset and ownership events as new blocks arrive
+    #[clap(short_flag = 'W')]
+    Watch(WatchCommand),
 }
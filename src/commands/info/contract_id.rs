@@ -0,0 +1,32 @@
+use crate::commands::utils::{ArtifactArgs, SaltArgs};
+use clap::Args;
+
+#[derive(Args, Clone)]
+#[command(about = "Predict offline the contract id of a deployment with a salt")]
+pub(crate) struct ContractIdCommand {
+    #[clap(flatten)]
+    pub(crate) salt: SaltArgs,
+
+    #[clap(flatten)]
+    pub(crate) artifacts: ArtifactArgs,
+}
+
+impl ContractIdCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let salt = match self.salt.salt()? {
+            Some(salt) => salt,
+            None => anyhow::bail!("Pass --salt or --salt-seed"),
+        };
+
+        let contract_id = self
+            .artifacts
+            .deploy_config()
+            .with_salt(salt)
+            .contract_id()?;
+
+        println!("\nSalt: 0x{}", salt);
+        println!("MultiAsset contract id: 0x{}", contract_id);
+
+        Ok(())
+    }
+}
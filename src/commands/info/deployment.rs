@@ -0,0 +1,45 @@
+use clap::Args;
+use fuels::prelude::Provider;
+use multiasset_sdk::Deployment;
+use std::path::PathBuf;
+
+#[derive(Args, Clone)]
+#[command(about = "Verify a deployment record against its deployment transaction")]
+pub(crate) struct DeploymentCommand {
+    /// The deployment record written by deploy
+    #[clap(long, default_value = "deployment.json")]
+    pub(crate) record: PathBuf,
+
+    /// The URL to query
+    /// Defaults to the network in the record
+    #[clap(long)]
+    pub(crate) rpc: Option<String>,
+}
+
+impl DeploymentCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let deployment = Deployment::load(&self.record)?;
+        let provider = Provider::connect(self.rpc.as_ref().unwrap_or(&deployment.network)).await?;
+
+        println!("\nNetwork: {}", deployment.network);
+        println!("Contract id: 0x{}", deployment.contract_id);
+        println!("Salt: 0x{}", deployment.salt);
+        println!("Deployer: 0x{}", deployment.deployer);
+        println!("Bytecode hash: 0x{}", deployment.bytecode_hash);
+        println!("Storage slots hash: 0x{}", deployment.storage_slots_hash);
+        println!("Block height: {}", deployment.block_height);
+        println!("Transaction id: 0x{}", deployment.tx_id);
+
+        let mismatches = deployment.verify(&provider).await?;
+        if mismatches.is_empty() {
+            println!("\nThe record matches the contract deployed on chain");
+            return Ok(());
+        }
+
+        println!();
+        for mismatch in mismatches.iter() {
+            println!("{}", mismatch);
+        }
+        anyhow::bail!("The record does not match the deployment on chain");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::commands::{
+    manifest::Manifest,
+    utils::{scan_from, setup, validate_contract_id, AssetUnits},
+};
+use clap::Args;
+use fuels::accounts::ViewOnlyAccount;
+use multiasset_sdk::{MultiAssetContract, MultiAssetEvent};
+use std::{fmt::Display, path::PathBuf};
+
+#[derive(Args, Clone)]
+#[command(about = "Compare assets declared in a manifest with the contract state")]
+pub(crate) struct DiffCommand {
+    /// The TOML file declaring assets and their allocations
+    /// Ex. assets.toml
+    #[clap(long)]
+    pub(crate) manifest: PathBuf,
+
+    /// The first block height to scan for assets missing from the manifest
+    /// Defaults to the block of the deployment record given as the contract id
+    #[clap(long)]
+    pub(crate) from: Option<u32>,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl DiffCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let manifest = Manifest::load(&self.manifest)?;
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+        let from = scan_from(&self.contract_id, self.from)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+
+        let mut differences: Vec<String> = vec![];
+        for declared in manifest.assets.iter() {
+            let asset = contract.asset_id(&declared.symbol);
+            if contract.asset(&declared.symbol).await?.value != Some(asset) {
+                differences.push(format!("missing {} 0x{}", declared.symbol, asset));
+                continue;
+            }
+
+            let mut compare = |field: &str, declared_value: &dyn Display, value: Option<String>| {
+                let value = value.unwrap_or_else(|| String::from("none"));
+                if declared_value.to_string() != value {
+                    differences.push(format!(
+                        "mismatched {} {}: declared {} on chain {}",
+                        declared.symbol, field, declared_value, value
+                    ));
+                }
+            };
+            compare("name", &declared.name, contract.name(&asset).await?.value);
+            compare(
+                "symbol",
+                &declared.symbol,
+                contract.symbol(&asset).await?.value,
+            );
+            compare(
+                "decimals",
+                &declared.decimals,
+                contract
+                    .decimals(&asset)
+                    .await?
+                    .value
+                    .map(|v| v.to_string()),
+            );
+            compare(
+                "restricted_mint",
+                &declared.restricted_mint,
+                contract
+                    .restricted_mint(&asset)
+                    .await?
+                    .value
+                    .map(|v| v.to_string()),
+            );
+
+            // Amounts are formatted with the declared decimals, as the manifest writes them
+            let units = AssetUnits {
+                decimals: declared.decimals,
+                symbol: declared.symbol.clone(),
+            };
+            let total_supply = contract.total_supply(&asset).await?.value.unwrap_or(0);
+            match declared.total_supply {
+                Some(supply) if supply != total_supply => differences.push(format!(
+                    "mismatched {} total_supply: declared {} on chain {}",
+                    declared.symbol,
+                    units.format(supply),
+                    units.format(total_supply)
+                )),
+                Some(_) => {}
+                None => {
+                    let allocated = declared.allocated()?;
+                    if total_supply < allocated {
+                        differences.push(format!(
+                            "mismatched {} total_supply: allocated {} on chain {}",
+                            declared.symbol,
+                            units.format(allocated),
+                            units.format(total_supply)
+                        ));
+                    }
+                }
+            }
+        }
+
+        // The contract has no asset enumeration, so discover assets from their creation events
+        let latest = wallet.try_provider()?.latest_block_height().await?;
+        for block_event in contract.events_in_range(from, latest).await? {
+            if let MultiAssetEvent::AssetNew(event) = block_event.event {
+                if !manifest
+                    .assets
+                    .iter()
+                    .any(|declared| contract.asset_id(&declared.symbol) == event.asset)
+                {
+                    differences.push(format!("unexpected {} 0x{}", event.symbol, event.asset));
+                }
+            }
+        }
+
+        if differences.is_empty() {
+            println!("\nThe contract matches the manifest");
+            return Ok(());
+        }
+
+        println!();
+        for difference in differences.iter() {
+            println!("{}", difference);
+        }
+        anyhow::bail!("{} differences from the manifest", differences.len());
+    }
+}
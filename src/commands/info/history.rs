@@ -0,0 +1,162 @@
+use crate::commands::utils::{
+    format_identity, setup, validate_contract_id, write_rows, AssetUnits, OutputFormat,
+};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, types::AssetId};
+use multiasset_sdk::{format_amount, BlockEvent, MultiAssetContract, MultiAssetEvent};
+use serde::Serialize;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    path::PathBuf,
+    str::FromStr,
+};
+
+#[derive(Args, Clone)]
+#[command(about = "Query asset creation and mint history in a block range")]
+pub(crate) struct HistoryCommand {
+    /// The asset id
+    /// Includes all assets if omitted
+    #[clap(long)]
+    pub(crate) asset: Option<String>,
+
+    /// The first block height to scan
+    #[clap(long)]
+    pub(crate) from: u32,
+
+    /// The last block height to scan
+    /// Defaults to the latest block
+    #[clap(long)]
+    pub(crate) to: Option<u32>,
+
+    /// The output format
+    #[clap(long, default_value = "csv")]
+    pub(crate) format: OutputFormat,
+
+    /// The file to export to
+    /// Prints to stdout if omitted
+    #[clap(long)]
+    pub(crate) output: Option<PathBuf>,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+/// An asset creation or mint in the contract history
+#[derive(Serialize)]
+pub(crate) struct HistoryRow {
+    pub(crate) block_height: u32,
+    pub(crate) tx_id: String,
+    pub(crate) event: &'static str,
+    pub(crate) asset: String,
+    pub(crate) symbol: Option<String>,
+    pub(crate) recipient: Option<String>,
+    pub(crate) amount: Option<u64>,
+    pub(crate) amount_formatted: Option<String>,
+    pub(crate) sender: String,
+}
+
+impl HistoryRow {
+    pub(crate) fn new(
+        block_event: &BlockEvent,
+        units: &HashMap<AssetId, AssetUnits>,
+    ) -> Option<Self> {
+        let tx_id = format!("0x{}", block_event.tx_id);
+        match &block_event.event {
+            MultiAssetEvent::AssetNew(event) => Some(Self {
+                block_height: block_event.block_height,
+                tx_id,
+                event: "AssetNew",
+                asset: format!("0x{}", event.asset),
+                symbol: Some(event.symbol.clone()),
+                recipient: None,
+                amount: None,
+                amount_formatted: None,
+                sender: format_identity(&event.creator),
+            }),
+            MultiAssetEvent::AssetMinted(event) => Some(Self {
+                block_height: block_event.block_height,
+                tx_id,
+                event: "AssetMinted",
+                asset: format!("0x{}", event.asset),
+                symbol: None,
+                recipient: Some(format_identity(&event.recipient)),
+                amount: Some(event.amount),
+                amount_formatted: units
+                    .get(&event.asset)
+                    .map(|units| format_amount(event.amount, units.decimals)),
+                sender: format_identity(&event.minter),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl HistoryCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        let asset_id = match &self.asset {
+            Some(asset) => {
+                if asset.len() as u64 != 66 {
+                    anyhow::bail!("Invalid fuel asset length");
+                }
+                Some(AssetId::from_str(asset).expect("Invalid asset"))
+            }
+            None => None,
+        };
+
+        let to = match self.to {
+            Some(to) => to,
+            None => wallet.try_provider()?.latest_block_height().await?,
+        };
+        if self.from > to {
+            anyhow::bail!("Invalid block range {}..{}", self.from, to);
+        }
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+
+        let events = contract.events_in_range(self.from, to).await?;
+
+        // Decimals of minted assets, fetched once per asset to format amounts
+        let mut units: HashMap<AssetId, AssetUnits> = HashMap::new();
+        for block_event in events.iter() {
+            if let MultiAssetEvent::AssetMinted(event) = &block_event.event {
+                if let Entry::Vacant(entry) = units.entry(event.asset) {
+                    entry.insert(AssetUnits::fetch(&contract, &event.asset).await?);
+                }
+            }
+        }
+
+        let rows: Vec<HistoryRow> = events
+            .iter()
+            .filter_map(|block_event| HistoryRow::new(block_event, &units))
+            .filter(|row| match asset_id {
+                Some(asset_id) => row.asset == format!("0x{}", asset_id),
+                None => true,
+            })
+            .collect();
+
+        write_rows(&rows, &self.format, self.output.as_deref())?;
+
+        if let Some(output) = &self.output {
+            println!(
+                "\n{} events from block {} to {} exported to: {}",
+                rows.len(),
+                self.from,
+                to,
+                output.display()
+            );
+        }
+
+        Ok(())
+    }
+}
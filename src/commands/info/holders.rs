@@ -0,0 +1,94 @@
+use crate::commands::utils::{
+    format_identity, scan_from, setup, validate_contract_id, write_rows, AssetUnits, OutputFormat,
+};
+use clap::Args;
+use fuels::{accounts::ViewOnlyAccount, types::AssetId};
+use multiasset_sdk::{format_amount, MultiAssetContract};
+use serde::Serialize;
+use std::{path::PathBuf, str::FromStr};
+
+#[derive(Args, Clone)]
+#[command(about = "Query current balances of every recipient of an asset")]
+pub(crate) struct HoldersCommand {
+    /// The asset id
+    #[clap(long)]
+    pub(crate) asset: String,
+
+    /// The first block height to scan for mints
+    /// Defaults to the block of the deployment record given as the contract id
+    #[clap(long)]
+    pub(crate) from: Option<u32>,
+
+    /// The output format
+    #[clap(long, default_value = "csv")]
+    pub(crate) format: OutputFormat,
+
+    /// The file to export to
+    /// Prints to stdout if omitted
+    #[clap(long)]
+    pub(crate) output: Option<PathBuf>,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+#[derive(Serialize)]
+struct HolderRow {
+    recipient: String,
+    minted: u64,
+    minted_formatted: String,
+    balance: u64,
+    balance_formatted: String,
+}
+
+impl HoldersCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+        let from = scan_from(&self.contract_id, self.from)?;
+
+        if self.asset.len() as u64 != 66 {
+            anyhow::bail!("Invalid fuel asset length");
+        }
+
+        let asset_id = AssetId::from_str(&self.asset).expect("Invalid asset");
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+        let units = AssetUnits::fetch(&contract, &asset_id).await?;
+
+        let latest = wallet.try_provider()?.latest_block_height().await?;
+        let rows: Vec<HolderRow> = contract
+            .holders_in_range(&asset_id, from, latest)
+            .await?
+            .iter()
+            .map(|holder| HolderRow {
+                recipient: format_identity(&holder.recipient),
+                minted: holder.minted,
+                minted_formatted: format_amount(holder.minted, units.decimals),
+                balance: holder.balance,
+                balance_formatted: format_amount(holder.balance, units.decimals),
+            })
+            .collect();
+
+        write_rows(&rows, &self.format, self.output.as_deref())?;
+
+        if let Some(output) = &self.output {
+            println!(
+                "\nAn asset {} holders as of block {} exported to: {}",
+                self.asset,
+                latest,
+                output.display()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use crate::commands::utils::{setup, validate_contract_id};
+use clap::Args;
+use fuels::types::AssetId;
+use multiasset_sdk::{Metadata, MultiAssetContract};
+use std::str::FromStr;
+
+#[derive(Args, Clone)]
+#[command(about = "Query an asset SRC-7 metadata entry")]
+pub(crate) struct MetadataCommand {
+    /// The asset id
+    #[clap(long)]
+    pub(crate) asset: String,
+
+    /// The metadata key
+    /// Ex. icon_url
+    #[clap(long)]
+    pub(crate) key: String,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl MetadataCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        if self.asset.len() as u64 != 66 {
+            anyhow::bail!("Invalid fuel asset length");
+        }
+
+        let asset_id = AssetId::from_str(&self.asset).expect("Invalid asset");
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+
+        let metadata = contract.metadata(&asset_id, &self.key).await?.value;
+
+        match metadata {
+            Some(Metadata::B256(value)) => println!(
+                "\nAn asset {} metadata {:?} is: 0x{}",
+                self.asset,
+                self.key,
+                hex::encode(value.0)
+            ),
+            Some(Metadata::Bytes(value)) => println!(
+                "\nAn asset {} metadata {:?} is: 0x{}",
+                self.asset,
+                self.key,
+                hex::encode(value.0)
+            ),
+            Some(Metadata::Int(value)) => println!(
+                "\nAn asset {} metadata {:?} is: {}",
+                self.asset, self.key, value
+            ),
+            Some(Metadata::String(value)) => println!(
+                "\nAn asset {} metadata {:?} is: {:?}",
+                self.asset, self.key, value
+            ),
+            None => println!(
+                "\nAn asset {} metadata {:?} is not set",
+                self.asset, self.key
+            ),
+        }
+
+        Ok(())
+    }
+}
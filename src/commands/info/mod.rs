@@ -1,8 +1,16 @@
 pub(crate) mod bech32_conv;
 pub(crate) mod cli;
+pub(crate) mod contract_id;
 pub(crate) mod decimals;
+pub(crate) mod deployment;
+pub(crate) mod diff;
+pub(crate) mod history;
+pub(crate) mod holders;
+pub(crate) mod metadata;
 pub(crate) mod name;
 pub(crate) mod restricted_mint;
 pub(crate) mod symbol;
 pub(crate) mod total_assets;
 pub(crate) mod total_supply;
+pub(crate) mod tx;
+pub(crate) mod watch;
@@ -12,6 +12,7 @@ pub(crate) struct SymbolCommand {
     pub(crate) asset: String,
 
     /// The contract id of the market
+    /// Or the path to its deployment record
     #[clap(long)]
     pub(crate) contract_id: String,
 
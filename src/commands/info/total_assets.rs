@@ -6,6 +6,7 @@ use multiasset_sdk::MultiAssetContract;
 #[command(about = "Query an asset number on contract")]
 pub(crate) struct TotalAssetsCommand {
     /// The contract id of the market
+    /// Or the path to its deployment record
     #[clap(long)]
     pub(crate) contract_id: String,
 
@@ -1,9 +1,8 @@
-use crate::commands::{
-    utils::{setup, validate_contract_id},
-};
+use crate::commands::utils::{setup, validate_contract_id, AssetUnits};
 use clap::Args;
 use fuels::types::AssetId;
-use multiasset_sdk::MultiAssetContract;
+use multiasset_sdk::{format_amount, MultiAssetContract};
+use serde_json::json;
 use std::str::FromStr;
 
 #[derive(Args, Clone)]
@@ -13,7 +12,12 @@ pub(crate) struct TotalSupplyCommand {
     #[clap(long)]
     pub(crate) asset: String,
 
+    /// Print a JSON object with the raw and formatted total supply
+    #[clap(long)]
+    pub(crate) json: bool,
+
     /// The contract id of the market
+    /// Or the path to its deployment record
     #[clap(long)]
     pub(crate) contract_id: String,
 
@@ -37,12 +41,27 @@ impl TotalSupplyCommand {
         // Connect to the deployed contract via the rpc
         let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
 
-        // Create a new asset
-        let total_supply = contract.total_supply(&asset_id).await?.value.unwrap();
+        let units = AssetUnits::fetch(&contract, &asset_id).await?;
+        let total_supply = contract.total_supply(&asset_id).await?.value.unwrap_or(0);
+
+        if self.json {
+            println!(
+                "{}",
+                json!({
+                    "asset": format!("0x{}", asset_id),
+                    "symbol": units.symbol,
+                    "decimals": units.decimals,
+                    "total_supply": total_supply,
+                    "total_supply_formatted": format_amount(total_supply, units.decimals),
+                })
+            );
+            return Ok(());
+        }
 
         println!(
             "\nAn asset 0x{} total supply is: {}",
-            self.asset, total_supply
+            self.asset,
+            units.format(total_supply)
         );
 
         Ok(())
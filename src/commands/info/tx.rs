@@ -0,0 +1,102 @@
+use crate::commands::{
+    info::watch::{human_line, json_value},
+    utils::{setup, validate_contract_id, AssetUnits},
+};
+use clap::Args;
+use fuels::{tx::TxId, types::AssetId};
+use multiasset_sdk::{MultiAssetContract, MultiAssetEvent, TxOutcome};
+use serde_json::json;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    str::FromStr,
+    time::Duration,
+};
+
+#[derive(Args, Clone)]
+#[command(about = "Query the status and contract events of a transaction")]
+pub(crate) struct TxCommand {
+    /// The transaction id
+    #[clap(long)]
+    pub(crate) id: String,
+
+    /// The seconds to wait for a pending transaction to be committed
+    /// Reports it as pending if omitted
+    #[clap(long)]
+    pub(crate) wait: Option<u64>,
+
+    /// Print the status and events as JSON
+    #[clap(long)]
+    pub(crate) json: bool,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl TxCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        if self.id.len() as u64 != 66 {
+            anyhow::bail!("Invalid transaction id length");
+        }
+        let tx_id = TxId::from_str(&self.id).map_err(anyhow::Error::msg)?;
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet).await;
+
+        let outcome = match self.wait {
+            Some(wait) => Some(contract.wait_for(&tx_id, Duration::from_secs(wait)).await?),
+            None => contract.tx_outcome(&tx_id).await?,
+        };
+
+        let (status, reason, events) = match &outcome {
+            None => ("pending", None, &[][..]),
+            Some(TxOutcome::Success { events, .. }) => ("success", None, &events[..]),
+            Some(TxOutcome::Reverted { reason, .. }) => ("reverted", Some(reason), &[][..]),
+            Some(TxOutcome::SqueezedOut { reason }) => ("squeezed out", Some(reason), &[][..]),
+        };
+
+        // Decimals and symbols of minted assets, fetched once per asset to format amounts
+        let mut units: HashMap<AssetId, AssetUnits> = HashMap::new();
+        for event in events {
+            if let MultiAssetEvent::AssetMinted(event) = event {
+                if let Entry::Vacant(entry) = units.entry(event.asset) {
+                    entry.insert(AssetUnits::fetch(&contract, &event.asset).await?);
+                }
+            }
+        }
+
+        if self.json {
+            let value = json!({
+                "tx_id": format!("0x{}", tx_id),
+                "status": status,
+                "reason": reason,
+                "events": events
+                    .iter()
+                    .filter_map(|event| json_value(event, &units))
+                    .collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&value)?);
+            return Ok(());
+        }
+
+        println!("\nTransaction: 0x{}", tx_id);
+        match reason {
+            Some(reason) => println!("Status: {}: {}", status, reason),
+            None => println!("Status: {}", status),
+        }
+        for line in events.iter().filter_map(|event| human_line(event, &units)) {
+            println!("{}", line);
+        }
+
+        Ok(())
+    }
+}
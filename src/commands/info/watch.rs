@@ -0,0 +1,226 @@
+use crate::commands::utils::{format_identity, setup, validate_contract_id, AssetUnits};
+use clap::{Args, ValueEnum};
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+use multiasset_sdk::{format_amount, BlockEvent, MultiAssetContract, MultiAssetEvent};
+use serde_json::json;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    str::FromStr,
+    time::Duration,
+};
+
+#[derive(Clone, ValueEnum)]
+pub(crate) enum WatchFormat {
+    /// One human readable line per event
+    Human,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Args, Clone)]
+#[command(about = "Stream asset and ownership events as new blocks arrive")]
+pub(crate) struct WatchCommand {
+    /// Only show events of this asset id
+    #[clap(long)]
+    pub(crate) asset: Option<String>,
+
+    /// Only show mints to this b256 recipient id
+    #[clap(long)]
+    pub(crate) recipient: Option<String>,
+
+    /// The block height to start streaming from
+    /// Defaults to the next block
+    #[clap(long)]
+    pub(crate) from: Option<u32>,
+
+    /// The seconds to wait between polls for new blocks
+    #[clap(long, default_value_t = 2)]
+    pub(crate) poll_interval: u64,
+
+    /// The output format
+    #[clap(long, default_value = "human")]
+    pub(crate) format: WatchFormat,
+
+    /// The contract id of the market
+    /// Or the path to its deployment record
+    #[clap(long)]
+    pub(crate) contract_id: String,
+
+    /// The URL to query
+    /// Ex. testnet.fuel.network
+    #[clap(long)]
+    pub(crate) rpc: String,
+}
+
+impl WatchCommand {
+    pub(crate) async fn run(&self) -> anyhow::Result<()> {
+        let wallet = setup(&self.rpc).await?;
+        let contract_id = validate_contract_id(&self.contract_id)?;
+
+        let asset_id = match &self.asset {
+            Some(asset) => {
+                if asset.len() as u64 != 66 {
+                    anyhow::bail!("Invalid fuel asset length");
+                }
+                Some(AssetId::from_str(asset).expect("Invalid asset"))
+            }
+            None => None,
+        };
+        let recipient = match &self.recipient {
+            Some(recipient) => {
+                if recipient.len() as u64 != 66 {
+                    anyhow::bail!("Invalid recipient id length");
+                }
+                Some(recipient.to_lowercase())
+            }
+            None => None,
+        };
+
+        // Connect to the deployed contract via the rpc
+        let contract = MultiAssetContract::new(contract_id, wallet.clone()).await;
+        let provider = wallet.try_provider()?;
+
+        let mut from = match self.from {
+            Some(from) => from,
+            None => provider.latest_block_height().await? + 1,
+        };
+        if let WatchFormat::Human = self.format {
+            println!("\nWatching from block: {}", from);
+        }
+
+        // Decimals and symbols of minted assets, fetched once per asset to format amounts
+        let mut units: HashMap<AssetId, AssetUnits> = HashMap::new();
+        loop {
+            let latest = provider.latest_block_height().await?;
+            if latest >= from {
+                for block_event in contract.events_in_range(from, latest).await? {
+                    if !self.matches(&block_event.event, asset_id, recipient.as_deref()) {
+                        continue;
+                    }
+                    if let MultiAssetEvent::AssetMinted(event) = &block_event.event {
+                        if let Entry::Vacant(entry) = units.entry(event.asset) {
+                            entry.insert(AssetUnits::fetch(&contract, &event.asset).await?);
+                        }
+                    }
+                    self.print(&block_event, &units);
+                }
+                from = latest + 1;
+            }
+            tokio::time::sleep(Duration::from_secs(self.poll_interval)).await;
+        }
+    }
+
+    fn matches(
+        &self,
+        event: &MultiAssetEvent,
+        asset_id: Option<AssetId>,
+        recipient: Option<&str>,
+    ) -> bool {
+        let (asset, to): (Option<AssetId>, Option<&Identity>) = match event {
+            MultiAssetEvent::AssetNew(event) => (Some(event.asset), None),
+            MultiAssetEvent::AssetMinted(event) => (Some(event.asset), Some(&event.recipient)),
+            MultiAssetEvent::OwnershipSet(_) | MultiAssetEvent::OwnershipTransferred(_) => {
+                (None, None)
+            }
+            _ => return false,
+        };
+
+        let asset_matches = asset_id.is_none_or(|asset_id| asset == Some(asset_id));
+        let recipient_matches =
+            recipient.is_none_or(|recipient| to.is_some_and(|to| format_identity(to) == recipient));
+        asset_matches && recipient_matches
+    }
+
+    fn print(&self, block_event: &BlockEvent, units: &HashMap<AssetId, AssetUnits>) {
+        match self.format {
+            WatchFormat::Human => {
+                if let Some(line) = human_line(&block_event.event, units) {
+                    println!(
+                        "[block {} tx 0x{}] {}",
+                        block_event.block_height, block_event.tx_id, line
+                    );
+                }
+            }
+            WatchFormat::Json => {
+                if let Some(mut value) = json_value(&block_event.event, units) {
+                    value["block_height"] = json!(block_event.block_height);
+                    value["tx_id"] = json!(format!("0x{}", block_event.tx_id));
+                    println!("{}", value);
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn human_line(
+    event: &MultiAssetEvent,
+    units: &HashMap<AssetId, AssetUnits>,
+) -> Option<String> {
+    match event {
+        MultiAssetEvent::AssetNew(event) => Some(format!(
+            "An asset 0x{} {:?} created by: {}",
+            event.asset,
+            event.symbol,
+            format_identity(&event.creator)
+        )),
+        MultiAssetEvent::AssetMinted(event) => Some(format!(
+            "An asset 0x{} amount minted {} to: {} by: {}",
+            event.asset,
+            units
+                .get(&event.asset)
+                .map(|units| units.format(event.amount))
+                .unwrap_or_else(|| event.amount.to_string()),
+            format_identity(&event.recipient),
+            format_identity(&event.minter)
+        )),
+        MultiAssetEvent::OwnershipSet(event) => Some(format!(
+            "Ownership set to: {}",
+            format_identity(&event.new_owner)
+        )),
+        MultiAssetEvent::OwnershipTransferred(event) => Some(format!(
+            "Ownership transferred from: {} to: {}",
+            format_identity(&event.previous_owner),
+            format_identity(&event.new_owner)
+        )),
+        _ => None,
+    }
+}
+
+pub(crate) fn json_value(
+    event: &MultiAssetEvent,
+    units: &HashMap<AssetId, AssetUnits>,
+) -> Option<serde_json::Value> {
+    match event {
+        MultiAssetEvent::AssetNew(event) => Some(json!({
+            "event": "AssetNew",
+            "asset": format!("0x{}", event.asset),
+            "name": event.name,
+            "symbol": event.symbol,
+            "decimals": event.decimals,
+            "creator": format_identity(&event.creator),
+        })),
+        MultiAssetEvent::AssetMinted(event) => Some(json!({
+            "event": "AssetMinted",
+            "asset": format!("0x{}", event.asset),
+            "recipient": format_identity(&event.recipient),
+            "amount": event.amount,
+            "amount_formatted": units
+                .get(&event.asset)
+                .map(|units| format_amount(event.amount, units.decimals)),
+            "minter": format_identity(&event.minter),
+        })),
+        MultiAssetEvent::OwnershipSet(event) => Some(json!({
+            "event": "OwnershipSet",
+            "new_owner": format_identity(&event.new_owner),
+        })),
+        MultiAssetEvent::OwnershipTransferred(event) => Some(json!({
+            "event": "OwnershipTransferred",
+            "previous_owner": format_identity(&event.previous_owner),
+            "new_owner": format_identity(&event.new_owner),
+        })),
+        _ => None,
+    }
+}
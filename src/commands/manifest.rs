@@ -0,0 +1,86 @@
+use crate::commands::utils::{parse_identity, IdentityType};
+use fuels::types::Identity;
+use serde::Deserialize;
+use std::{collections::HashSet, path::Path};
+
+/// The assets a contract should hold, read from a TOML file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Manifest {
+    #[serde(default)]
+    pub(crate) assets: Vec<ManifestAsset>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ManifestAsset {
+    pub(crate) name: String,
+    pub(crate) symbol: String,
+    pub(crate) decimals: u8,
+    #[serde(default)]
+    pub(crate) restricted_mint: bool,
+    /// The exact supply expected on chain, at least the allocations sum if omitted
+    pub(crate) total_supply: Option<u64>,
+    #[serde(default)]
+    pub(crate) allocations: Vec<Allocation>,
+}
+
+/// A balance of the asset a recipient should hold at least
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Allocation {
+    pub(crate) recipient_id: String,
+    pub(crate) recipient_type: IdentityType,
+    pub(crate) amount: u64,
+}
+
+impl Manifest {
+    pub(crate) fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Failed to read {}: {}", path.display(), error))?;
+        let manifest: Self = toml::from_str(&content)?;
+
+        let mut symbols = HashSet::new();
+        for asset in manifest.assets.iter() {
+            if asset.name.is_empty() || asset.symbol.is_empty() {
+                anyhow::bail!("Asset name and symbol must not be empty");
+            }
+            if asset.decimals > 12 {
+                anyhow::bail!("Asset {} decimals must be at most 12", asset.symbol);
+            }
+            if !symbols.insert(asset.symbol.as_str()) {
+                anyhow::bail!("Asset {} is declared more than once", asset.symbol);
+            }
+            for allocation in asset.allocations.iter() {
+                allocation.recipient()?;
+            }
+            let allocated = asset.allocated()?;
+            if asset.total_supply.is_some_and(|supply| supply < allocated) {
+                anyhow::bail!(
+                    "Asset {} total supply is below its allocations",
+                    asset.symbol
+                );
+            }
+        }
+
+        Ok(manifest)
+    }
+}
+
+impl ManifestAsset {
+    /// The sum of all allocation amounts
+    pub(crate) fn allocated(&self) -> anyhow::Result<u64> {
+        self.allocations
+            .iter()
+            .try_fold(0u64, |total, allocation| {
+                total.checked_add(allocation.amount)
+            })
+            .ok_or_else(|| anyhow::anyhow!("Asset {} allocations overflow", self.symbol))
+    }
+}
+
+impl Allocation {
+    pub(crate) fn recipient(&self) -> anyhow::Result<Identity> {
+        parse_identity(&self.recipient_id, &self.recipient_type)
+    }
+}
@@ -1,4 +1,9 @@
 pub(crate) mod cli;
 pub(crate) mod core;
+#[cfg(feature = "indexer")]
+pub(crate) mod indexer;
 pub(crate) mod info;
+pub(crate) mod manifest;
+pub(crate) mod preflight;
+pub(crate) mod recipients;
 pub(crate) mod utils;
@@ -0,0 +1,168 @@
+use crate::commands::utils::format_identity;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::WalletUnlocked,
+    types::{
+        errors::{transaction::Reason, Error},
+        AssetId, Identity,
+    },
+};
+use multiasset_sdk::{CostEstimate, MultiAssetContract, State};
+
+/// Fails unless the signer owns the contract, naming the actual owner
+pub(crate) async fn check_owner(
+    contract: &MultiAssetContract,
+    wallet: &WalletUnlocked,
+) -> anyhow::Result<()> {
+    let signer: Identity = wallet.address().into();
+    match contract.owner().await?.value {
+        State::Initialized(owner) if owner == signer => Ok(()),
+        State::Initialized(owner) => anyhow::bail!(
+            "Signer {} is not the contract owner {}",
+            format_identity(&signer),
+            format_identity(&owner)
+        ),
+        State::Uninitialized => anyhow::bail!("The contract owner is not initialized"),
+        State::Revoked => anyhow::bail!("The contract ownership is revoked"),
+    }
+}
+
+/// Whether the contract, or `asset` when given, is paused. Contracts deployed before
+/// pausing existed revert on `is_paused`, and they can never be paused.
+async fn is_paused(contract: &MultiAssetContract, asset: Option<AssetId>) -> anyhow::Result<bool> {
+    match contract.is_paused(asset).await {
+        Ok(response) => Ok(response.value),
+        Err(error)
+            if matches!(
+                error.downcast_ref::<Error>(),
+                Some(Error::Transaction(Reason::Reverted { .. }))
+            ) =>
+        {
+            Ok(false)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Fails if the contract, or `asset` when given, is paused
+pub(crate) async fn check_not_paused(
+    contract: &MultiAssetContract,
+    asset: Option<AssetId>,
+) -> anyhow::Result<()> {
+    if is_paused(contract, None).await? {
+        anyhow::bail!("The contract is paused");
+    }
+    if let Some(asset) = asset {
+        if is_paused(contract, Some(asset)).await? {
+            anyhow::bail!("An asset 0x{} is paused", asset);
+        }
+    }
+    Ok(())
+}
+
+/// Fails if `asset` does not exist, otherwise returns whether only the owner can mint it
+pub(crate) async fn check_asset(
+    contract: &MultiAssetContract,
+    asset: &AssetId,
+) -> anyhow::Result<bool> {
+    match contract.restricted_mint(asset).await?.value {
+        Some(restricted_mint) => Ok(restricted_mint),
+        None => anyhow::bail!("An asset 0x{} does not exist", asset),
+    }
+}
+
+/// Fails if the signer's base asset balance is below `fee`
+pub(crate) async fn check_fee(wallet: &WalletUnlocked, fee: u64) -> anyhow::Result<()> {
+    let balance = wallet
+        .get_asset_balance(wallet.try_provider()?.base_asset_id())
+        .await?;
+    if balance < fee {
+        anyhow::bail!(
+            "Signer base asset balance {} does not cover the estimated fee {}",
+            balance,
+            fee
+        );
+    }
+    Ok(())
+}
+
+/// Checks `asset_new` can succeed and its fee is covered
+pub(crate) async fn check_asset_new(
+    contract: &MultiAssetContract,
+    wallet: &WalletUnlocked,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    restricted_mint: bool,
+) -> anyhow::Result<()> {
+    check_owner(contract, wallet).await?;
+    check_not_paused(contract, None).await?;
+    if let Some(asset) = contract.asset(symbol).await?.value {
+        anyhow::bail!("An asset {} already exists with id: 0x{}", symbol, asset);
+    }
+
+    let estimate = contract
+        .estimate_asset_new(name, symbol, decimals, restricted_mint)
+        .await?;
+    check_fee(wallet, estimate.fee).await
+}
+
+/// Checks `count` mints of `amount` can succeed and their fees are covered.
+/// Returns the estimated cost of all mints.
+pub(crate) async fn check_mint(
+    contract: &MultiAssetContract,
+    wallet: &WalletUnlocked,
+    recipient: Identity,
+    asset: &AssetId,
+    amount: u64,
+    count: u64,
+) -> anyhow::Result<CostEstimate> {
+    if amount == 0 {
+        anyhow::bail!("The amount to mint must be positive");
+    }
+    if check_asset(contract, asset).await? {
+        check_owner(contract, wallet)
+            .await
+            .map_err(|error| anyhow::anyhow!("Minting is restricted to the owner: {}", error))?;
+    }
+    check_not_paused(contract, Some(*asset)).await?;
+
+    let estimate = contract
+        .estimate_mint_many(recipient, asset, amount, count)
+        .await?;
+    check_fee(wallet, estimate.fee).await?;
+    Ok(estimate)
+}
+
+/// Checks every `(recipient, asset, amount)` entry of a batch can be minted
+/// and the fee of the batch transaction is covered. Returns its estimated cost.
+pub(crate) async fn check_mint_batch(
+    contract: &MultiAssetContract,
+    wallet: &WalletUnlocked,
+    mints: &[(Identity, AssetId, u64)],
+) -> anyhow::Result<CostEstimate> {
+    if mints.is_empty() {
+        anyhow::bail!("No mints given");
+    }
+    if mints.iter().any(|(_, _, amount)| *amount == 0) {
+        anyhow::bail!("The amount to mint must be positive");
+    }
+
+    let mut checked: Vec<AssetId> = vec![];
+    for (_, asset, _) in mints.iter() {
+        if checked.contains(asset) {
+            continue;
+        }
+        if check_asset(contract, asset).await? {
+            check_owner(contract, wallet).await.map_err(|error| {
+                anyhow::anyhow!("Minting 0x{} is restricted to the owner: {}", asset, error)
+            })?;
+        }
+        check_not_paused(contract, Some(*asset)).await?;
+        checked.push(*asset);
+    }
+
+    let estimate = contract.estimate_mint_batch(mints).await?;
+    check_fee(wallet, estimate.fee).await?;
+    Ok(estimate)
+}
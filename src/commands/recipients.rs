@@ -0,0 +1,52 @@
+use crate::commands::utils::{parse_identity, IdentityType};
+use fuels::types::{AssetId, Identity};
+use serde::Deserialize;
+use std::{path::Path, str::FromStr};
+
+/// One line of a recipient file: an asset amount sent to a recipient
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RecipientRow {
+    pub(crate) recipient_id: String,
+    pub(crate) recipient_type: IdentityType,
+    pub(crate) asset: String,
+    /// In whole tokens, or base units with `--base-units`
+    pub(crate) amount: String,
+}
+
+impl RecipientRow {
+    /// Reads a CSV file with a `recipient_id,recipient_type,asset,amount` header
+    pub(crate) fn load(path: &Path) -> anyhow::Result<Vec<Self>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_path(path)
+            .map_err(|error| anyhow::anyhow!("Failed to read {}: {}", path.display(), error))?;
+
+        let mut rows = vec![];
+        for (line, row) in reader.deserialize::<Self>().enumerate() {
+            // Line 1 is the header
+            let row =
+                row.map_err(|error| anyhow::anyhow!("Invalid line {}: {}", line + 2, error))?;
+            row.recipient()
+                .and(row.asset())
+                .map_err(|error| anyhow::anyhow!("Invalid line {}: {}", line + 2, error))?;
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            anyhow::bail!("No recipients in {}", path.display());
+        }
+
+        Ok(rows)
+    }
+
+    pub(crate) fn recipient(&self) -> anyhow::Result<Identity> {
+        parse_identity(&self.recipient_id, &self.recipient_type)
+    }
+
+    pub(crate) fn asset(&self) -> anyhow::Result<AssetId> {
+        if self.asset.len() as u64 != 66 {
+            anyhow::bail!("Invalid fuel asset length: {}", self.asset);
+        }
+        AssetId::from_str(&self.asset).map_err(anyhow::Error::msg)
+    }
+}
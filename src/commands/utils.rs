@@ -1,6 +1,19 @@
-use clap::ValueEnum;
-use fuels::prelude::{ContractId, Provider, WalletUnlocked};
-use std::str::FromStr;
+use clap::{Args, ValueEnum};
+use fuels::{
+    prelude::{ContractId, Provider, Salt, TxPolicies, WalletUnlocked},
+    types::{Address, AssetId, Identity},
+};
+use multiasset_sdk::{
+    format_amount, parse_amount, DeployConfig, Deployment, MultiAssetContract, RetryPolicy,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
 
 pub(crate) async fn setup(rpc: &str) -> anyhow::Result<WalletUnlocked> {
     let provider = Provider::connect(rpc).await?;
@@ -11,6 +24,11 @@ pub(crate) async fn setup(rpc: &str) -> anyhow::Result<WalletUnlocked> {
 }
 
 pub(crate) fn validate_contract_id(contract_id: &str) -> anyhow::Result<ContractId> {
+    // Anything other than a hex id is read as the path to a deployment record
+    if !contract_id.starts_with("0x") && Path::new(contract_id).is_file() {
+        return Ok(Deployment::load(contract_id)?.contract_id);
+    }
+
     if contract_id.len() as u64 != 66 {
         anyhow::bail!("Invalid contract id length");
     }
@@ -18,10 +36,259 @@ pub(crate) fn validate_contract_id(contract_id: &str) -> anyhow::Result<Contract
     Ok(ContractId::from_str(contract_id).expect("Invalid contract id"))
 }
 
-#[derive(Clone, ValueEnum)]
+/// The first block to scan for contract events: `from` if given, otherwise the block
+/// of the deployment record passed as the contract id
+pub(crate) fn scan_from(contract_id: &str, from: Option<u32>) -> anyhow::Result<u32> {
+    if let Some(from) = from {
+        return Ok(from);
+    }
+    if !contract_id.starts_with("0x") && Path::new(contract_id).is_file() {
+        return Ok(Deployment::load(contract_id)?.block_height);
+    }
+    anyhow::bail!(
+        "Pass --from or a deployment record as the contract id, \
+        instead of scanning every block from genesis"
+    )
+}
+
+#[derive(Args, Clone)]
+pub(crate) struct TxPolicyArgs {
+    /// The tip for the block producer in base asset units
+    /// Raise it when the network is congested
+    #[clap(long)]
+    pub(crate) tip: Option<u64>,
+
+    /// The gas limit of each transaction script
+    #[clap(long)]
+    pub(crate) gas_limit: Option<u64>,
+
+    /// The maximum fee of each transaction in base asset units
+    #[clap(long)]
+    pub(crate) max_fee: Option<u64>,
+
+    /// The block height before which transactions cannot be included
+    #[clap(long)]
+    pub(crate) maturity: Option<u64>,
+}
+
+impl TxPolicyArgs {
+    pub(crate) fn tx_policies(&self) -> TxPolicies {
+        TxPolicies::new(self.tip, None, self.maturity, self.max_fee, self.gas_limit)
+    }
+}
+
+#[derive(Args, Clone)]
+pub(crate) struct RetryArgs {
+    /// The times to resend a transaction that timed out or lost its coins to another
+    /// transaction. Its status is checked first, so it never lands twice.
+    #[clap(long, default_value_t = 3)]
+    pub(crate) retries: u32,
+
+    /// The milliseconds to wait before the first retry, doubled after each one
+    #[clap(long, default_value_t = 1000)]
+    pub(crate) backoff_ms: u64,
+}
+
+impl RetryArgs {
+    pub(crate) fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(
+            self.retries.saturating_add(1),
+            Duration::from_millis(self.backoff_ms),
+        )
+    }
+}
+
+#[derive(Args, Clone)]
+pub(crate) struct ArtifactArgs {
+    /// The contract binary to deploy
+    /// Defaults to the binary built into this tool
+    #[clap(long)]
+    pub(crate) binary: Option<PathBuf>,
+
+    /// The storage slots JSON of the contract binary
+    /// Defaults to the storage slots built into this tool
+    #[clap(long)]
+    pub(crate) storage_slots: Option<PathBuf>,
+}
+
+impl ArtifactArgs {
+    pub(crate) fn deploy_config(&self) -> DeployConfig {
+        let mut config = DeployConfig::new();
+        if let Some(binary) = &self.binary {
+            config = config.with_binary_path(binary);
+        }
+        if let Some(storage_slots) = &self.storage_slots {
+            config = config.with_storage_slots_path(storage_slots);
+        }
+        config
+    }
+}
+
+#[derive(Args, Clone)]
+pub(crate) struct SaltArgs {
+    /// The b256 salt of the deployment
+    /// Random if neither salt option is given
+    #[clap(long, conflicts_with = "salt_seed")]
+    pub(crate) salt: Option<String>,
+
+    /// A string to derive the salt from
+    /// Ex. testnet-staging
+    #[clap(long)]
+    pub(crate) salt_seed: Option<String>,
+}
+
+impl SaltArgs {
+    pub(crate) fn salt(&self) -> anyhow::Result<Option<Salt>> {
+        if let Some(salt) = &self.salt {
+            if salt.len() as u64 != 66 {
+                anyhow::bail!("Invalid salt length");
+            }
+            return Ok(Some(Salt::from_str(salt).map_err(anyhow::Error::msg)?));
+        }
+        Ok(self
+            .salt_seed
+            .as_deref()
+            .map(MultiAssetContract::salt_from_seed))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
 pub(crate) enum IdentityType {
     /// Externally Owned Account
     Address,
     /// Contract
     Contract,
 }
+
+#[derive(Clone, ValueEnum)]
+pub(crate) enum MetadataType {
+    /// b256 value in hex
+    B256,
+    /// Bytes in hex
+    Bytes,
+    /// u64 value
+    Int,
+    /// UTF-8 string
+    String,
+}
+
+#[derive(Clone, ValueEnum)]
+pub(crate) enum OutputFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// JSON array
+    Json,
+}
+
+/// The decimals and symbol of an asset, to read and show amounts in whole tokens
+pub(crate) struct AssetUnits {
+    pub(crate) decimals: u8,
+    pub(crate) symbol: String,
+}
+
+impl AssetUnits {
+    pub(crate) async fn fetch(
+        contract: &MultiAssetContract,
+        asset: &AssetId,
+    ) -> anyhow::Result<Self> {
+        let decimals = match contract.decimals(asset).await?.value {
+            Some(decimals) => decimals,
+            None => anyhow::bail!("An asset 0x{} does not exist", asset),
+        };
+        let symbol = contract.symbol(asset).await?.value.unwrap_or_default();
+        Ok(Self { decimals, symbol })
+    }
+
+    /// The units of the chain base asset, which the contract does not know
+    pub(crate) fn base() -> Self {
+        Self {
+            decimals: 9,
+            symbol: String::from("ETH"),
+        }
+    }
+
+    /// Parses an amount such as `1.5` or `1.5BTC` into base units,
+    /// or a plain integer of base units if `base_units` is set
+    pub(crate) fn parse(&self, amount: &str, base_units: bool) -> anyhow::Result<u64> {
+        let amount = amount.trim();
+        if base_units {
+            return amount
+                .parse::<u64>()
+                .map_err(|_| anyhow::anyhow!("Invalid base units amount {:?}", amount));
+        }
+
+        let split = amount
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(amount.len());
+        let (number, symbol) = amount.split_at(split);
+        let symbol = symbol.trim();
+        if !symbol.is_empty() && !symbol.eq_ignore_ascii_case(&self.symbol) {
+            anyhow::bail!(
+                "Amount {} is not in the asset symbol {}",
+                amount,
+                self.symbol
+            );
+        }
+        parse_amount(number, self.decimals)
+    }
+
+    /// Formats base units as whole tokens with the symbol, e.g. `1.5 BTC`
+    pub(crate) fn format(&self, amount: u64) -> String {
+        format!("{} {}", format_amount(amount, self.decimals), self.symbol)
+    }
+}
+
+/// Parses a b256 recipient id of the given type
+pub(crate) fn parse_identity(
+    recipient_id: &str,
+    recipient_type: &IdentityType,
+) -> anyhow::Result<Identity> {
+    if recipient_id.len() as u64 != 66 {
+        anyhow::bail!("Invalid recipient id length: {}", recipient_id);
+    }
+    Ok(match recipient_type {
+        IdentityType::Address => {
+            Identity::Address(Address::from_str(recipient_id).map_err(anyhow::Error::msg)?)
+        }
+        IdentityType::Contract => {
+            Identity::ContractId(ContractId::from_str(recipient_id).map_err(anyhow::Error::msg)?)
+        }
+    })
+}
+
+pub(crate) fn format_identity(identity: &Identity) -> String {
+    match identity {
+        Identity::Address(address) => format!("0x{}", address),
+        Identity::ContractId(contract_id) => format!("0x{}", contract_id),
+    }
+}
+
+/// Writes rows to a file, or to stdout if no file is given
+pub(crate) fn write_rows<T: Serialize>(
+    rows: &[T],
+    format: &OutputFormat,
+    output: Option<&Path>,
+) -> anyhow::Result<()> {
+    let writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(std::io::stdout()),
+    };
+
+    match format {
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for row in rows {
+                writer.serialize(row)?;
+            }
+            writer.flush()?;
+        }
+        OutputFormat::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, rows)?;
+            writeln!(writer)?;
+        }
+    }
+
+    Ok(())
+}
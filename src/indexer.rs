@@ -0,0 +1,289 @@
+use crate::{MultiAssetContract, MultiAssetEvent};
+use fuels::types::{Address, AssetId, ContractId, Identity};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use std::{path::Path, str::FromStr};
+
+/// Blocks scanned per database transaction, so an interrupted sync loses at most one batch
+const SYNC_BATCH_BLOCKS: u32 = 1000;
+
+/// Digits of `u64::MAX`. SQLite integers are signed, so amounts are stored as text
+/// zero-padded to this width, which keeps them in numeric order.
+const AMOUNT_DIGITS: usize = 20;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS cursor (
+        contract_id TEXT PRIMARY KEY,
+        block_height INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS assets (
+        asset TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        symbol TEXT NOT NULL,
+        decimals INTEGER NOT NULL,
+        creator TEXT NOT NULL,
+        block_height INTEGER NOT NULL,
+        tx_id TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS mints (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        asset TEXT NOT NULL,
+        recipient TEXT NOT NULL,
+        recipient_type TEXT NOT NULL,
+        amount TEXT NOT NULL,
+        minter TEXT NOT NULL,
+        block_height INTEGER NOT NULL,
+        tx_id TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS holders (
+        asset TEXT NOT NULL,
+        recipient TEXT NOT NULL,
+        recipient_type TEXT NOT NULL,
+        total TEXT NOT NULL,
+        PRIMARY KEY (asset, recipient)
+    );
+";
+
+/// An asset indexed from an `AssetNew` event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedAsset {
+    pub asset: AssetId,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub block_height: u32,
+}
+
+/// Persists `AssetNew` and `AssetMinted` events of one contract in a SQLite database
+pub struct Indexer {
+    connection: Connection,
+    contract_id: ContractId,
+}
+
+impl Indexer {
+    pub fn open(path: impl AsRef<Path>, contract_id: ContractId) -> anyhow::Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(SCHEMA)?;
+
+        let indexed: Option<String> = connection
+            .query_row(
+                "SELECT contract_id FROM cursor WHERE contract_id != ?1",
+                params![format!("0x{}", contract_id)],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(indexed) = indexed {
+            anyhow::bail!("Database already indexes contract {}", indexed);
+        }
+
+        Ok(Self {
+            connection,
+            contract_id,
+        })
+    }
+
+    /// The last indexed block height
+    pub fn cursor(&self) -> anyhow::Result<Option<u32>> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT block_height FROM cursor WHERE contract_id = ?1",
+                params![format!("0x{}", self.contract_id)],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Indexes blocks after the cursor, or from `start_block` on the first sync, up to `to_block`.
+    /// Returns the new cursor.
+    pub async fn sync(
+        &mut self,
+        contract: &MultiAssetContract,
+        start_block: u32,
+        to_block: u32,
+    ) -> anyhow::Result<u32> {
+        let mut from = match self.cursor()? {
+            Some(cursor) => cursor + 1,
+            None => start_block,
+        };
+
+        while from <= to_block {
+            let to = to_block.min(from.saturating_add(SYNC_BATCH_BLOCKS - 1));
+            let events = contract.events_in_range(from, to).await?;
+
+            let tx = self.connection.transaction()?;
+            for block_event in events.iter() {
+                let tx_id = format!("0x{}", block_event.tx_id);
+                match &block_event.event {
+                    MultiAssetEvent::AssetNew(event) => {
+                        tx.execute(
+                            "INSERT OR REPLACE INTO assets
+                                (asset, name, symbol, decimals, creator, block_height, tx_id)
+                                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                            params![
+                                format!("0x{}", event.asset),
+                                event.name,
+                                event.symbol,
+                                event.decimals,
+                                identity_hex(&event.creator),
+                                block_event.block_height,
+                                tx_id,
+                            ],
+                        )?;
+                    }
+                    MultiAssetEvent::AssetMinted(event) => {
+                        insert_mint(&tx, event, block_event.block_height, &tx_id)?;
+                    }
+                    _ => {}
+                }
+            }
+            tx.execute(
+                "INSERT OR REPLACE INTO cursor (contract_id, block_height) VALUES (?1, ?2)",
+                params![format!("0x{}", self.contract_id), to],
+            )?;
+            tx.commit()?;
+
+            from = to + 1;
+        }
+
+        Ok(self.cursor()?.unwrap_or(to_block))
+    }
+
+    /// All indexed assets in creation order
+    pub fn assets(&self) -> anyhow::Result<Vec<IndexedAsset>> {
+        let mut statement = self.connection.prepare(
+            "SELECT asset, name, symbol, decimals, block_height FROM assets
+                ORDER BY block_height, rowid",
+        )?;
+        let rows = statement.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+            ))
+        })?;
+
+        let mut assets = vec![];
+        for row in rows {
+            let (asset, name, symbol, decimals, block_height) = row?;
+            assets.push(IndexedAsset {
+                asset: AssetId::from_str(&asset).map_err(anyhow::Error::msg)?,
+                name,
+                symbol,
+                decimals,
+                block_height,
+            });
+        }
+        Ok(assets)
+    }
+
+    /// Every recipient of `asset` with the total amount minted to it, largest first
+    pub fn holders(&self, asset: &AssetId) -> anyhow::Result<Vec<(Identity, u64)>> {
+        let mut statement = self.connection.prepare(
+            "SELECT recipient, recipient_type, total FROM holders
+                WHERE asset = ?1 ORDER BY total DESC, recipient",
+        )?;
+        let rows = statement.query_map(params![format!("0x{}", asset)], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut holders = vec![];
+        for row in rows {
+            let (recipient, recipient_type, total) = row?;
+            holders.push((
+                parse_identity(&recipient, &recipient_type)?,
+                parse_amount_text(&total)?,
+            ));
+        }
+        Ok(holders)
+    }
+}
+
+fn insert_mint(
+    tx: &Transaction,
+    event: &crate::AssetMinted,
+    block_height: u32,
+    tx_id: &str,
+) -> anyhow::Result<()> {
+    let asset = format!("0x{}", event.asset);
+    let recipient = identity_hex(&event.recipient);
+    let recipient_type = identity_type(&event.recipient);
+
+    tx.execute(
+        "INSERT INTO mints
+            (asset, recipient, recipient_type, amount, minter, block_height, tx_id)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            asset,
+            recipient,
+            recipient_type,
+            amount_text(event.amount),
+            identity_hex(&event.minter),
+            block_height,
+            tx_id,
+        ],
+    )?;
+
+    let total = match tx
+        .query_row(
+            "SELECT total FROM holders WHERE asset = ?1 AND recipient = ?2",
+            params![asset, recipient],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()?
+    {
+        Some(total) => parse_amount_text(&total)?,
+        None => 0,
+    };
+    let total = total
+        .checked_add(event.amount)
+        .ok_or_else(|| anyhow::anyhow!("Minted total overflow for {} of {}", recipient, asset))?;
+    tx.execute(
+        "INSERT OR REPLACE INTO holders (asset, recipient, recipient_type, total)
+            VALUES (?1, ?2, ?3, ?4)",
+        params![asset, recipient, recipient_type, amount_text(total)],
+    )?;
+
+    Ok(())
+}
+
+fn amount_text(amount: u64) -> String {
+    format!("{:0width$}", amount, width = AMOUNT_DIGITS)
+}
+
+fn parse_amount_text(amount: &str) -> anyhow::Result<u64> {
+    amount
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid indexed amount {:?}", amount))
+}
+
+fn identity_hex(identity: &Identity) -> String {
+    match identity {
+        Identity::Address(address) => format!("0x{}", address),
+        Identity::ContractId(contract_id) => format!("0x{}", contract_id),
+    }
+}
+
+fn identity_type(identity: &Identity) -> &'static str {
+    match identity {
+        Identity::Address(_) => "address",
+        Identity::ContractId(_) => "contract",
+    }
+}
+
+fn parse_identity(hex: &str, identity_type: &str) -> anyhow::Result<Identity> {
+    match identity_type {
+        "address" => Ok(Identity::Address(
+            Address::from_str(hex).map_err(anyhow::Error::msg)?,
+        )),
+        "contract" => Ok(Identity::ContractId(
+            ContractId::from_str(hex).map_err(anyhow::Error::msg)?,
+        )),
+        _ => anyhow::bail!("Unknown identity type {}", identity_type),
+    }
+}
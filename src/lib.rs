@@ -1,68 +1,668 @@
-use fuels::types::{bech32::Bech32ContractId, AssetId, Bytes32, ContractId, Identity};
+use fuel_tx::{Output, TxPointer, UtxoId};
+use fuels::types::{
+    bech32::Bech32ContractId, coin::Coin, coin_type::CoinType, coin_type_id::CoinTypeId,
+    input::Input, Address, AssetId, Bytes32, ContractId, Identity, Salt,
+};
 use fuels::{
-    prelude::{
-        abigen, Contract, LoadConfiguration, StorageConfiguration, TxPolicies,
-        VariableOutputPolicy, WalletUnlocked,
+    accounts::{Account, ViewOnlyAccount},
+    client::{FuelClient, PageDirection, PaginationRequest},
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+        Configurables,
+    },
+    prelude::{abigen, Contract, Provider, TxPolicies, VariableOutputPolicy, WalletUnlocked},
+    programs::{
+        calls::{CallHandler, ContractCall, ContractDependency, Execution},
+        contract::Regular,
+        responses::CallResponse,
+    },
+    tx::{Receipt, StorageSlot, TxId},
+    types::{
+        errors::{transaction::Reason, Error},
+        transaction::{ScriptTransaction, Transaction, TransactionType},
+        transaction_builders::{
+            BuildableTransaction, CreateTransactionBuilder, ScriptTransactionBuilder,
+            TransactionBuilder,
+        },
+        tx_status::TxStatus,
     },
-    programs::{calls::Execution, responses::CallResponse},
 };
 
 use rand::Rng;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+pub mod amount;
+#[cfg(feature = "indexer")]
+pub mod indexer;
+pub mod retry;
+
+pub use amount::{format_amount, parse_amount};
+pub use retry::{RetryPolicy, SendFailure};
 
 abigen!(Contract(
     name = "MultiAsset",
     abi = "contract/out/release/multiasset-contract-abi.json"
 ));
 
-const MULTIASSET_CONTRACT_BINARY_PATH: &str = "contract/out/release/multiasset-contract.bin";
-const MULTIASSET_CONTRACT_STORAGE_PATH: &str =
-    "contract/out/release/multiasset-contract-storage_slots.json";
+/// The compiled contract, embedded so the published crate deploys without the source tree
+const MULTIASSET_CONTRACT_BINARY: &[u8] =
+    include_bytes!("../contract/out/release/multiasset-contract.bin");
+const MULTIASSET_CONTRACT_STORAGE_SLOTS: &str =
+    include_str!("../contract/out/release/multiasset-contract-storage_slots.json");
+
+/// An event logged by the MultiAsset contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultiAssetEvent {
+    AssetNew(AssetNew),
+    AssetMinted(AssetMinted),
+    SetName(SetNameEvent),
+    SetSymbol(SetSymbolEvent),
+    SetDecimals(SetDecimalsEvent),
+    TotalSupply(TotalSupplyEvent),
+    SetMetadata(SetMetadataEvent),
+    OwnershipSet(OwnershipSet),
+    OwnershipTransferred(OwnershipTransferred),
+    Paused(Paused),
+    Unpaused(Unpaused),
+}
+
+impl MultiAssetEvent {
+    fn decode(decoder: &LogDecoder, receipt: &Receipt) -> anyhow::Result<Option<Self>> {
+        let receipts = std::slice::from_ref(receipt);
+
+        macro_rules! decode {
+            ($($event:ident => $variant:ident),* $(,)?) => {
+                $(
+                    if let Some(event) = decoder.decode_logs_with_type::<$event>(receipts)?.pop() {
+                        return Ok(Some(Self::$variant(event)));
+                    }
+                )*
+            };
+        }
+
+        decode!(
+            AssetNew => AssetNew,
+            AssetMinted => AssetMinted,
+            SetNameEvent => SetName,
+            SetSymbolEvent => SetSymbol,
+            SetDecimalsEvent => SetDecimals,
+            TotalSupplyEvent => TotalSupply,
+            SetMetadataEvent => SetMetadata,
+            OwnershipSet => OwnershipSet,
+            OwnershipTransferred => OwnershipTransferred,
+            Paused => Paused,
+            Unpaused => Unpaused,
+        );
+
+        Ok(None)
+    }
+}
+
+/// An event logged by the contract in a committed transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockEvent {
+    pub block_height: u32,
+    pub tx_id: TxId,
+    pub event: MultiAssetEvent,
+}
+
+/// A recipient of mints of an asset with its current balance
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Holder {
+    pub recipient: Identity,
+    pub minted: u64,
+    pub balance: u64,
+}
+
+/// The final status of a submitted transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxOutcome {
+    /// Committed, with the events logged by the contract
+    Success {
+        receipts: Vec<Receipt>,
+        events: Vec<MultiAssetEvent>,
+    },
+    /// Committed but reverted, with the error the contract logged, e.g. `NotOwner`
+    Reverted {
+        reason: String,
+        revert_id: u64,
+        receipts: Vec<Receipt>,
+    },
+    /// Dropped by the node without being committed, e.g. after its coins were spent
+    SqueezedOut { reason: String },
+}
+
+/// A record of a contract deployment, written so the contract can be found and verified later
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployment {
+    /// The URL of the node the contract was deployed through
+    pub network: String,
+    pub contract_id: ContractId,
+    pub salt: Salt,
+    pub deployer: Address,
+    /// The sha256 of the deployed bytecode
+    pub bytecode_hash: Bytes32,
+    /// The sha256 of the initial storage slots, see `storage_slots_hash`
+    pub storage_slots_hash: Bytes32,
+    pub block_height: u32,
+    pub tx_id: TxId,
+}
+
+impl Deployment {
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Failed to read {}: {}", path.display(), error))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let mut content = serde_json::to_string_pretty(self)?;
+        content.push('\n');
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Compares the record with the contract on chain now and with its deployment transaction.
+    /// Returns a description of every field that does not match.
+    pub async fn verify(&self, provider: &Provider) -> anyhow::Result<Vec<String>> {
+        let mut mismatches = vec![];
+
+        // The provider has no contract query, so ask the node directly
+        let client = FuelClient::new(provider.url())?;
+        match client.contract(&self.contract_id).await? {
+            Some(contract) => {
+                let bytecode_hash = sha256(&contract.bytecode);
+                if bytecode_hash != self.bytecode_hash {
+                    mismatches.push(format!(
+                        "contract bytecode hash: recorded 0x{} on chain 0x{}",
+                        self.bytecode_hash, bytecode_hash
+                    ));
+                }
+            }
+            None => mismatches.push(format!("contract 0x{} not found", self.contract_id)),
+        }
+
+        let response = match provider.get_transaction_by_id(&self.tx_id).await? {
+            Some(response) => response,
+            None => {
+                mismatches.push(format!("transaction 0x{} not found", self.tx_id));
+                return Ok(mismatches);
+            }
+        };
+        let tx = match response.transaction {
+            TransactionType::Create(tx) => tx,
+            _ => {
+                mismatches.push(format!(
+                    "transaction 0x{} is not a contract deployment",
+                    self.tx_id
+                ));
+                return Ok(mismatches);
+            }
+        };
+
+        if !matches!(response.status, TxStatus::Success { .. }) {
+            mismatches.push(format!("transaction 0x{} did not succeed", self.tx_id));
+        }
+        if let Some(block_height) = response.block_height {
+            if *block_height != self.block_height {
+                mismatches.push(format!(
+                    "block height: recorded {} on chain {}",
+                    self.block_height, *block_height
+                ));
+            }
+        }
+
+        let code = tx
+            .witnesses()
+            .get(tx.bytecode_witness_index() as usize)
+            .map(|witness| witness.as_vec().clone())
+            .unwrap_or_default();
+        let bytecode_hash = sha256(&code);
+        if bytecode_hash != self.bytecode_hash {
+            mismatches.push(format!(
+                "bytecode hash: recorded 0x{} on chain 0x{}",
+                self.bytecode_hash, bytecode_hash
+            ));
+        }
+        let storage_slots_hash = storage_slots_hash(tx.storage_slots());
+        if storage_slots_hash != self.storage_slots_hash {
+            mismatches.push(format!(
+                "storage slots hash: recorded 0x{} on chain 0x{}",
+                self.storage_slots_hash, storage_slots_hash
+            ));
+        }
+        if *tx.salt() != self.salt {
+            mismatches.push(format!(
+                "salt: recorded 0x{} on chain 0x{}",
+                self.salt,
+                tx.salt()
+            ));
+        }
+        let contract_id =
+            Contract::regular(code, *tx.salt(), tx.storage_slots().clone()).contract_id();
+        if contract_id != self.contract_id {
+            mismatches.push(format!(
+                "contract id: recorded 0x{} on chain 0x{}",
+                self.contract_id, contract_id
+            ));
+        }
+
+        Ok(mismatches)
+    }
+}
+
+fn sha256(data: &[u8]) -> Bytes32 {
+    let hash: [u8; 32] = Sha256::digest(data).into();
+    Bytes32::new(hash)
+}
+
+/// The sha256 of storage slots ordered by key, each hashed as its key followed by its value
+pub fn storage_slots_hash(storage_slots: &[StorageSlot]) -> Bytes32 {
+    let mut storage_slots = storage_slots.to_vec();
+    storage_slots.sort_by_key(|slot| *slot.key());
+
+    let mut hasher = Sha256::new();
+    for slot in storage_slots.iter() {
+        hasher.update(slot.key());
+        hasher.update(slot.value());
+    }
+    let hash: [u8; 32] = hasher.finalize().into();
+    Bytes32::new(hash)
+}
+
+/// How to deploy the contract, by default the embedded artifacts with a random salt
+#[derive(Clone, Debug, Default)]
+pub struct DeployConfig {
+    binary_path: Option<PathBuf>,
+    storage_slots_path: Option<PathBuf>,
+    salt: Option<Salt>,
+    tx_policies: TxPolicies,
+    configurables: Configurables,
+}
+
+impl DeployConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deploys the `.bin` at `path` instead of the embedded binary
+    pub fn with_binary_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.binary_path = Some(path.into());
+        self
+    }
+
+    /// Initializes storage from the storage slots JSON at `path` instead of the embedded slots
+    pub fn with_storage_slots_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.storage_slots_path = Some(path.into());
+        self
+    }
+
+    pub fn with_salt(mut self, salt: Salt) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+
+    pub fn with_configurables(mut self, configurables: impl Into<Configurables>) -> Self {
+        self.configurables = configurables.into();
+        self
+    }
+
+    /// Computes offline the id of the contract this configuration deploys.
+    /// Requires a salt.
+    pub fn contract_id(&self) -> anyhow::Result<ContractId> {
+        let salt = self
+            .salt
+            .ok_or_else(|| anyhow::anyhow!("A salt is required to predict the contract id"))?;
+        Ok(self.load(salt)?.contract_id())
+    }
+
+    fn load(&self, salt: Salt) -> anyhow::Result<Contract<Regular>> {
+        let code = match &self.binary_path {
+            Some(path) => std::fs::read(path)
+                .map_err(|error| anyhow::anyhow!("Failed to read {}: {}", path.display(), error))?,
+            None => MULTIASSET_CONTRACT_BINARY.to_vec(),
+        };
+        let storage_slots = match &self.storage_slots_path {
+            Some(path) => std::fs::read_to_string(path)
+                .map_err(|error| anyhow::anyhow!("Failed to read {}: {}", path.display(), error))?,
+            None => MULTIASSET_CONTRACT_STORAGE_SLOTS.to_string(),
+        };
+        let storage_slots: Vec<StorageSlot> = serde_json::from_str(&storage_slots)?;
+
+        Ok(Contract::regular(code, salt, storage_slots)
+            .with_configurables(self.configurables.clone()))
+    }
+}
+
+const TXS_PAGE_SIZE: i32 = 100;
+const TX_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The simulated cost of one or more transactions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CostEstimate {
+    pub gas_used: u64,
+    /// The fee in base asset units
+    pub fee: u64,
+}
+
+impl CostEstimate {
+    async fn of<D: Tokenizable + Parameterize + std::fmt::Debug>(
+        call: MultiAssetCall<D>,
+    ) -> anyhow::Result<Self> {
+        let cost = call.estimate_transaction_cost(None, None).await?;
+        Ok(Self {
+            gas_used: cost.gas_used,
+            fee: cost.total_fee,
+        })
+    }
+
+    /// The cost of sending the same transaction `n` times
+    pub fn times(&self, n: u64) -> anyhow::Result<Self> {
+        Ok(Self {
+            gas_used: self
+                .gas_used
+                .checked_mul(n)
+                .ok_or_else(|| anyhow::anyhow!("Gas estimate overflow"))?,
+            fee: self
+                .fee
+                .checked_mul(n)
+                .ok_or_else(|| anyhow::anyhow!("Fee estimate overflow"))?,
+        })
+    }
+}
+
+/// An unsent call to the MultiAsset contract returning `D`
+pub type MultiAssetCall<D> = CallHandler<WalletUnlocked, ContractCall, D>;
 
 pub struct MultiAssetContract {
     instance: MultiAsset<WalletUnlocked>,
+    tx_policies: TxPolicies,
+    retry_policy: RetryPolicy,
 }
 
 impl MultiAssetContract {
     pub async fn deploy(wallet: &WalletUnlocked) -> anyhow::Result<Self> {
-        let mut rng = rand::thread_rng();
-        let salt = rng.gen::<[u8; 32]>();
+        Ok(Self::deploy_with_config(wallet, &DeployConfig::new())
+            .await?
+            .0)
+    }
+
+    /// Deploys the contract with `salt`, so the same salt always yields the same contract id
+    pub async fn deploy_with_salt(wallet: &WalletUnlocked, salt: Salt) -> anyhow::Result<Self> {
+        Ok(Self::deploy_with_record(wallet, salt).await?.0)
+    }
+
+    /// Derives a salt from a human readable seed such as an environment name
+    pub fn salt_from_seed(seed: &str) -> Salt {
+        Salt::new(*sha256(seed.as_bytes()))
+    }
+
+    /// Computes offline the id the contract gets when deployed with `salt`
+    pub fn predict_contract_id(salt: Salt) -> anyhow::Result<ContractId> {
+        DeployConfig::new().with_salt(salt).contract_id()
+    }
 
-        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        let storage_configuration = StorageConfiguration::default()
-            .add_slot_overrides_from_file(root.join(MULTIASSET_CONTRACT_STORAGE_PATH));
+    /// Deploys the contract with `salt` and returns the record of the deployment
+    pub async fn deploy_with_record(
+        wallet: &WalletUnlocked,
+        salt: Salt,
+    ) -> anyhow::Result<(Self, Deployment)> {
+        Self::deploy_with_config(wallet, &DeployConfig::new().with_salt(salt)).await
+    }
+
+    /// Deploys the contract as configured and returns the record of the deployment
+    pub async fn deploy_with_config(
+        wallet: &WalletUnlocked,
+        config: &DeployConfig,
+    ) -> anyhow::Result<(Self, Deployment)> {
+        let salt = match config.salt {
+            Some(salt) => salt,
+            None => rand::thread_rng().gen::<[u8; 32]>().into(),
+        };
+        let contract = config.load(salt)?;
 
-        let contract_configuration =
-            LoadConfiguration::default().with_storage_configuration(storage_configuration?);
+        // Build the deployment transaction here rather than with `Contract::deploy`
+        // to learn its id for the record
+        let contract_id = contract.contract_id();
+        let code = contract.code();
+        let storage_slots = contract.storage_slots().to_vec();
+        let mut builder = CreateTransactionBuilder::prepare_contract_deployment(
+            code.clone(),
+            contract_id,
+            contract.state_root(),
+            contract.salt(),
+            storage_slots.clone(),
+            config.tx_policies,
+        )
+        .with_max_fee_estimation_tolerance(0.05);
+        wallet.add_witnesses(&mut builder)?;
+        wallet.adjust_for_fee(&mut builder, 0).await?;
 
-        let contract_id = Contract::load_from(
-            root.join(MULTIASSET_CONTRACT_BINARY_PATH),
-            contract_configuration,
-        )?
-        .with_salt(salt)
-        .deploy(wallet, TxPolicies::default())
-        .await?;
+        let provider = wallet.try_provider()?;
+        let tx = builder.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+        provider
+            .send_transaction_and_await_commit(tx)
+            .await?
+            .check(None)?;
+
+        let block_height = match provider.get_transaction_by_id(&tx_id).await? {
+            Some(response) => response.block_height.map(|height| *height),
+            None => None,
+        };
+        let block_height = match block_height {
+            Some(block_height) => block_height,
+            None => provider.latest_block_height().await?,
+        };
+
+        let deployment = Deployment {
+            network: provider.url().to_string(),
+            contract_id,
+            salt: contract.salt(),
+            deployer: wallet.address().into(),
+            bytecode_hash: sha256(&code),
+            storage_slots_hash: storage_slots_hash(&storage_slots),
+            block_height,
+            tx_id,
+        };
 
-        let multiasset = MultiAsset::new(contract_id.clone(), wallet.clone());
+        let multiasset = MultiAsset::new(contract_id, wallet.clone());
 
         let _self = Self {
             instance: multiasset,
+            retry_policy: RetryPolicy::default(),
+            tx_policies: config.tx_policies,
         };
 
         _self.initialize_ownership(wallet.address().into()).await?;
 
-        Ok(_self)
+        Ok((_self, deployment))
     }
 
     pub async fn new(contract_id: ContractId, wallet: WalletUnlocked) -> Self {
         Self {
             instance: MultiAsset::new(contract_id, wallet),
+            tx_policies: TxPolicies::default(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
     pub fn with_account(&self, account: &WalletUnlocked) -> Self {
         Self {
             instance: self.instance.clone().with_account(account.clone()),
+            tx_policies: self.tx_policies,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    /// Uses `tx_policies` for every transaction the contract sends, such as a tip or gas limit
+    pub fn with_tx_policies(&self, tx_policies: TxPolicies) -> Self {
+        Self {
+            instance: self.instance.clone(),
+            tx_policies,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    /// Retries every transaction the contract sends that fails for a transient reason
+    /// or a coin conflict, as set by `retry_policy`
+    pub fn with_retry_policy(&self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            instance: self.instance.clone(),
+            tx_policies: self.tx_policies,
+            retry_policy,
+        }
+    }
+
+    /// Sends `call` under the retry policy, see `send_with`
+    async fn send<D: Tokenizable + Parameterize + std::fmt::Debug>(
+        &self,
+        call: MultiAssetCall<D>,
+    ) -> anyhow::Result<CallResponse<D>> {
+        self.send_with(&call, || async { Ok(call.build_tx().await?) }, true)
+            .await
+    }
+
+    /// Sends the transaction `build` makes for `call` under the retry policy. After a failure
+    /// the status of the sent transaction is checked first, so a transaction that did land
+    /// is never sent twice. With `reselect_coins`, a coin conflict runs `build` again to select
+    /// new coins; without it, `build` always spends the same coins and a conflict is fatal.
+    async fn send_with<D, F, Fut>(
+        &self,
+        call: &MultiAssetCall<D>,
+        build: F,
+        reselect_coins: bool,
+    ) -> anyhow::Result<CallResponse<D>>
+    where
+        D: Tokenizable + Parameterize + std::fmt::Debug,
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = anyhow::Result<ScriptTransaction>>,
+    {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+
+        let mut tx = build().await?;
+        let mut attempt = 1;
+        loop {
+            let tx_id = tx.id(provider.chain_id());
+            let error = match provider.send_transaction_and_await_commit(tx.clone()).await {
+                Ok(status) => return Self::response(call, tx_id, status),
+                Err(error) => error,
+            };
+
+            // A timeout may hide a transaction that reached the node
+            match provider.tx_status(&tx_id).await {
+                Ok(TxStatus::Submitted) => {
+                    let status = provider
+                        .await_transaction_commit::<ScriptTransaction>(tx_id)
+                        .await?;
+                    return Self::response(call, tx_id, status);
+                }
+                Ok(status @ (TxStatus::Success { .. } | TxStatus::Revert { .. })) => {
+                    return Self::response(call, tx_id, status);
+                }
+                Ok(TxStatus::SqueezedOut { .. }) | Err(_) => {}
+            }
+
+            let failure = match SendFailure::of(&error) {
+                SendFailure::CoinConflict if !reselect_coins => SendFailure::Fatal,
+                failure => failure,
+            };
+            if failure == SendFailure::Fatal || attempt >= self.retry_policy.attempts {
+                return Err(error.into());
+            }
+            tokio::time::sleep(self.retry_policy.delay(attempt)).await;
+            attempt += 1;
+
+            // Resending the same transaction can never commit it twice,
+            // only spent coins require selecting new ones
+            if failure == SendFailure::CoinConflict {
+                tx = build().await?;
+            }
+        }
+    }
+
+    fn response<D: Tokenizable + Parameterize + std::fmt::Debug>(
+        call: &MultiAssetCall<D>,
+        tx_id: TxId,
+        status: TxStatus,
+    ) -> anyhow::Result<CallResponse<D>> {
+        let mut response = call.get_response_from(status)?;
+        response.tx_id = Some(tx_id);
+        Ok(response)
+    }
+
+    /// Sends `call` once and returns its id as soon as the node accepts it,
+    /// without waiting for it to be committed
+    async fn submit<D: Tokenizable + Parameterize + std::fmt::Debug>(
+        &self,
+        call: MultiAssetCall<D>,
+    ) -> anyhow::Result<TxId> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        let tx = call.build_tx().await?;
+        Ok(provider.send_transaction(tx).await?)
+    }
+
+    /// The outcome of a transaction, or `None` while it is still waiting in the pool
+    pub async fn tx_outcome(&self, tx_id: &TxId) -> anyhow::Result<Option<TxOutcome>> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        Ok(match provider.tx_status(tx_id).await? {
+            TxStatus::Submitted => None,
+            TxStatus::Success { receipts } => Some(TxOutcome::Success {
+                events: self.decode_events(&receipts)?,
+                receipts,
+            }),
+            TxStatus::SqueezedOut { reason } => Some(TxOutcome::SqueezedOut { reason }),
+            status @ TxStatus::Revert { .. } => {
+                // Decodes the logged error of a failed `require`, e.g. `NotOwner`
+                let Err(Error::Transaction(Reason::Reverted {
+                    reason,
+                    revert_id,
+                    receipts,
+                })) = status.check(Some(&self.instance.log_decoder()))
+                else {
+                    anyhow::bail!("Unexpected revert status of 0x{}", tx_id);
+                };
+                Some(TxOutcome::Reverted {
+                    reason,
+                    revert_id,
+                    receipts,
+                })
+            }
+        })
+    }
+
+    /// Polls a submitted transaction until it is committed or dropped,
+    /// failing if it is still pending after `timeout`
+    pub async fn wait_for(&self, tx_id: &TxId, timeout: Duration) -> anyhow::Result<TxOutcome> {
+        let started = Instant::now();
+        loop {
+            if let Some(outcome) = self.tx_outcome(tx_id).await? {
+                return Ok(outcome);
+            }
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                anyhow::bail!(
+                    "Transaction 0x{} still pending after {}s",
+                    tx_id,
+                    timeout.as_secs_f64()
+                );
+            }
+            tokio::time::sleep(TX_POLL_INTERVAL.min(timeout - elapsed)).await;
         }
     }
 
@@ -74,55 +674,788 @@ impl MultiAssetContract {
         self.instance.contract_id()
     }
 
-    async fn initialize_ownership(&self, recipient: Identity) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
+    /// Computes offline the id `asset_new` assigns to an asset with `symbol`
+    pub fn asset_id(&self, symbol: &str) -> AssetId {
+        let contract_id = ContractId::from(self.instance.contract_id());
+        let sub_id: [u8; 32] = Sha256::new()
+            .chain_update(contract_id)
+            .chain_update(symbol.as_bytes())
+            .finalize()
+            .into();
+        let asset: [u8; 32] = Sha256::new()
+            .chain_update(contract_id)
+            .chain_update(sub_id)
+            .finalize()
+            .into();
+        AssetId::new(asset)
+    }
+
+    /// Decodes all events logged by the contract in a call, in the order they were logged
+    pub fn events<D>(&self, response: &CallResponse<D>) -> anyhow::Result<Vec<MultiAssetEvent>> {
+        self.decode_events(&response.receipts)
+    }
+
+    /// Decodes all events logged by the contract in receipts, in the order they were logged
+    pub fn decode_events(&self, receipts: &[Receipt]) -> anyhow::Result<Vec<MultiAssetEvent>> {
+        let decoder = self.instance.log_decoder();
+        let mut events = vec![];
+        for receipt in receipts {
+            if let Some(event) = MultiAssetEvent::decode(&decoder, receipt)? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+
+    /// Decodes all events logged by the contract in a successful transaction.
+    /// A reverted, dropped or pending transaction has no events, as none took effect.
+    pub async fn tx_events(&self, tx_id: &TxId) -> anyhow::Result<Vec<MultiAssetEvent>> {
+        match self
             .instance
+            .account()
+            .try_provider()?
+            .tx_status(tx_id)
+            .await?
+        {
+            TxStatus::Success { receipts } => self.decode_events(&receipts),
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// Decodes all events logged by the contract in successful transactions
+    /// from `from_block` to `to_block` inclusive, in the order they were logged.
+    /// Transactions are fetched in pages with their receipts, and only those taking
+    /// this contract as an input are decoded.
+    pub async fn events_in_range(
+        &self,
+        from_block: u32,
+        to_block: u32,
+    ) -> anyhow::Result<Vec<BlockEvent>> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        let contract_id = ContractId::from(self.instance.contract_id());
+
+        // The cursor is exclusive, so the scan starts after the last transaction
+        // of the block before `from_block`
+        let mut cursor = match from_block.checked_sub(1) {
+            Some(height) => match provider.block_by_height(height.into()).await? {
+                Some(block) => block
+                    .transactions
+                    .last()
+                    .map(|tx_id| format!("{}#{}", height, tx_id)),
+                None => return Ok(vec![]),
+            },
+            None => None,
+        };
+
+        let mut events = vec![];
+        loop {
+            let page = provider
+                .get_transactions(PaginationRequest {
+                    cursor,
+                    results: TXS_PAGE_SIZE,
+                    direction: PageDirection::Forward,
+                })
+                .await?;
+
+            for response in page.results {
+                let Some(block_height) = response.block_height.map(u32::from) else {
+                    continue;
+                };
+                if block_height > to_block {
+                    return Ok(events);
+                }
+                // Only scripts call contracts, and every contract they touch is an input
+                let TransactionType::Script(tx) = response.transaction else {
+                    continue;
+                };
+                if !tx
+                    .inputs()
+                    .iter()
+                    .any(|input| input.contract_id() == Some(&contract_id))
+                {
+                    continue;
+                }
+                // Logs of reverted transactions never took effect
+                if let TxStatus::Success { receipts } = response.status {
+                    let tx_id = tx.id(provider.chain_id());
+                    for event in self.decode_events(&receipts)? {
+                        events.push(BlockEvent {
+                            block_height,
+                            tx_id,
+                            event,
+                        });
+                    }
+                }
+            }
+
+            if !page.has_next_page {
+                return Ok(events);
+            }
+            cursor = page.cursor;
+        }
+    }
+
+    /// Every recipient of `asset` mints with its current balance, largest balance first.
+    /// Scans from the block of `deployment`, as no mint can be older.
+    pub async fn holders(
+        &self,
+        asset: &AssetId,
+        deployment: &Deployment,
+    ) -> anyhow::Result<Vec<Holder>> {
+        if deployment.contract_id != ContractId::from(self.instance.contract_id()) {
+            anyhow::bail!(
+                "The deployment record is of contract 0x{}",
+                deployment.contract_id
+            );
+        }
+        let account = self.instance.account();
+        let latest = account.try_provider()?.latest_block_height().await?;
+        self.holders_in_range(asset, deployment.block_height, latest)
+            .await
+    }
+
+    /// Every recipient of `asset` mints from `from_block` to `to_block` inclusive,
+    /// with its current balance, largest balance first
+    pub async fn holders_in_range(
+        &self,
+        asset: &AssetId,
+        from_block: u32,
+        to_block: u32,
+    ) -> anyhow::Result<Vec<Holder>> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+
+        let mut holders: Vec<Holder> = vec![];
+        for block_event in self.events_in_range(from_block, to_block).await? {
+            if let MultiAssetEvent::AssetMinted(event) = block_event.event {
+                if event.asset != *asset {
+                    continue;
+                }
+                match holders
+                    .iter_mut()
+                    .find(|holder| holder.recipient == event.recipient)
+                {
+                    Some(holder) => holder.minted = holder.minted.saturating_add(event.amount),
+                    None => holders.push(Holder {
+                        recipient: event.recipient,
+                        minted: event.amount,
+                        balance: 0,
+                    }),
+                }
+            }
+        }
+
+        for holder in holders.iter_mut() {
+            holder.balance = match holder.recipient {
+                Identity::Address(address) => {
+                    provider.get_asset_balance(&address.into(), *asset).await?
+                }
+                Identity::ContractId(contract_id) => {
+                    provider
+                        .get_contract_asset_balance(&contract_id.into(), *asset)
+                        .await?
+                }
+            };
+        }
+        holders.sort_by_key(|holder| std::cmp::Reverse(holder.balance));
+
+        Ok(holders)
+    }
+
+    async fn initialize_ownership(&self, recipient: Identity) -> anyhow::Result<CallResponse<()>> {
+        self.send(
+            self.instance
+                .methods()
+                .initialize_ownership(recipient)
+                .with_tx_policies(self.tx_policies),
+        )
+        .await
+    }
+
+    /// Builds an unsent `transfer_ownership` call, e.g. to combine it with other calls
+    pub fn transfer_ownership_call(&self, recipient: Identity) -> MultiAssetCall<()> {
+        self.instance
             .methods()
-            .initialize_ownership(recipient)
-            .call()
-            .await?)
+            .transfer_ownership(recipient)
+            .with_tx_policies(self.tx_policies)
     }
 
     pub async fn transfer_ownership(
         &self,
         recipient: Identity,
     ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+        self.send(self.transfer_ownership_call(recipient)).await
+    }
+
+    /// Sends `transfer_ownership` without waiting for it, see `wait_for`
+    pub async fn submit_transfer_ownership(&self, recipient: Identity) -> anyhow::Result<TxId> {
+        self.submit(self.transfer_ownership_call(recipient)).await
+    }
+
+    /// Builds an unsent `asset_new` call, e.g. to combine it with other calls
+    pub fn asset_new_call(
+        &self,
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+        restricted_mint: bool,
+    ) -> MultiAssetCall<AssetId> {
+        self.instance
             .methods()
-            .transfer_ownership(recipient)
-            .call()
-            .await?)
+            .asset_new(
+                name.to_string(),
+                symbol.to_string(),
+                decimals,
+                restricted_mint,
+            )
+            .with_tx_policies(self.tx_policies)
     }
 
     pub async fn asset_new(
         &self,
-        name: &String,
-        symbol: &String,
+        name: &str,
+        symbol: &str,
         decimals: u8,
         restricted_mint: bool,
     ) -> anyhow::Result<CallResponse<AssetId>> {
-        Ok(self
-            .instance
+        self.send(self.asset_new_call(name, symbol, decimals, restricted_mint))
+            .await
+    }
+
+    /// Sends `asset_new` without waiting for it, see `wait_for`.
+    /// The new id is known upfront from `asset_id(symbol)`.
+    pub async fn submit_asset_new(
+        &self,
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+        restricted_mint: bool,
+    ) -> anyhow::Result<TxId> {
+        self.submit(self.asset_new_call(name, symbol, decimals, restricted_mint))
+            .await
+    }
+
+    /// Builds an unsent `asset_new_batch` call of `(name, symbol, decimals, restricted_mint)` entries
+    pub fn asset_new_batch_call(
+        &self,
+        assets: &[(&str, &str, u8, bool)],
+    ) -> MultiAssetCall<Vec<AssetId>> {
+        let assets = assets
+            .iter()
+            .map(|(name, symbol, decimals, restricted_mint)| {
+                (
+                    name.to_string(),
+                    symbol.to_string(),
+                    *decimals,
+                    *restricted_mint,
+                )
+            })
+            .collect();
+        self.instance
             .methods()
-            .asset_new(name.clone(), symbol.clone(), decimals, restricted_mint)
-            .call()
-            .await?)
+            .asset_new_batch(assets)
+            .with_tx_policies(self.tx_policies)
     }
 
-    pub async fn mint(
+    /// Creates every `(name, symbol, decimals, restricted_mint)` asset in one transaction
+    /// and returns their ids in order. Nothing is created if any symbol already exists.
+    pub async fn asset_new_batch(
+        &self,
+        assets: &[(&str, &str, u8, bool)],
+    ) -> anyhow::Result<CallResponse<Vec<AssetId>>> {
+        self.send(self.asset_new_batch_call(assets)).await
+    }
+
+    /// Sends `asset_new_batch` without waiting for it, see `wait_for`
+    pub async fn submit_asset_new_batch(
+        &self,
+        assets: &[(&str, &str, u8, bool)],
+    ) -> anyhow::Result<TxId> {
+        self.submit(self.asset_new_batch_call(assets)).await
+    }
+
+    /// Simulates `asset_new` and returns its gas and fee without sending it
+    pub async fn estimate_asset_new(
+        &self,
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+        restricted_mint: bool,
+    ) -> anyhow::Result<CostEstimate> {
+        CostEstimate::of(self.asset_new_call(name, symbol, decimals, restricted_mint)).await
+    }
+
+    /// Simulates `mint` and returns its gas and fee without sending it
+    pub async fn estimate_mint(
         &self,
         recipient: Identity,
         asset: &AssetId,
         amount: u64,
-    ) -> anyhow::Result<CallResponse<()>> {
-        Ok(self
-            .instance
+    ) -> anyhow::Result<CostEstimate> {
+        CostEstimate::of(self.mint_call(recipient, asset, amount)).await
+    }
+
+    /// Estimates `n` separate `mint` transactions of `amount`, as sent by an airdrop.
+    /// Extrapolates a single simulated `mint` instead of simulating all `n`, so it assumes
+    /// every mint costs the same as the first.
+    pub async fn estimate_mint_many(
+        &self,
+        recipient: Identity,
+        asset: &AssetId,
+        amount: u64,
+        n: u64,
+    ) -> anyhow::Result<CostEstimate> {
+        self.estimate_mint(recipient, asset, amount).await?.times(n)
+    }
+
+    /// Builds an unsent `mint` call with the variable output for the minted coins
+    pub fn mint_call(
+        &self,
+        recipient: Identity,
+        asset: &AssetId,
+        amount: u64,
+    ) -> MultiAssetCall<()> {
+        self.instance
             .methods()
             .mint(recipient, *asset, amount)
             .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
-            .call()
-            .await?)
+            .with_tx_policies(self.tx_policies)
+    }
+
+    pub async fn mint(
+        &self,
+        recipient: Identity,
+        asset: &AssetId,
+        amount: u64,
+    ) -> anyhow::Result<CallResponse<()>> {
+        self.send(self.mint_call(recipient, asset, amount)).await
+    }
+
+    /// Sends `mint` without waiting for it, see `wait_for`
+    pub async fn submit_mint(
+        &self,
+        recipient: Identity,
+        asset: &AssetId,
+        amount: u64,
+    ) -> anyhow::Result<TxId> {
+        self.submit(self.mint_call(recipient, asset, amount)).await
+    }
+
+    /// Mints like `mint`, but pays the fee only from `coin`, so that mints each holding
+    /// their own coin, e.g. from `split_coins`, can be sent in parallel without conflicts.
+    /// What is left of `coin` returns to the account as change. Fails without retrying if
+    /// `coin` is already spent.
+    pub async fn mint_with_coin(
+        &self,
+        recipient: Identity,
+        asset: &AssetId,
+        amount: u64,
+        coin: Coin,
+    ) -> anyhow::Result<CallResponse<()>> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+
+        let call = self.mint_call(recipient, asset, amount);
+        let (call, coin) = (&call, &coin);
+        self.send_with(
+            call,
+            || async {
+                let mut tx_builder = call.transaction_builder().await?;
+                tx_builder
+                    .inputs_mut()
+                    .push(Input::resource_signed(CoinType::Coin(coin.clone())));
+
+                // The call spends no base asset of its own, so it has no change output for it
+                let has_change = tx_builder.outputs().iter().any(|output| {
+                matches!(output, Output::Change { asset_id, .. } if *asset_id == base_asset_id)
+            });
+                if !has_change {
+                    tx_builder.outputs_mut().push(Output::change(
+                        account.address().into(),
+                        0,
+                        base_asset_id,
+                    ));
+                }
+                account.add_witnesses(&mut tx_builder)?;
+
+                Ok(tx_builder.build(provider).await?)
+            },
+            false,
+        )
+        .await
+    }
+
+    /// Splits the account's base asset into `count` new coins of `amount` each, paid to
+    /// itself in as few transactions as the output limit allows, and returns the new coins
+    pub async fn split_coins(&self, count: usize, amount: u64) -> anyhow::Result<Vec<Coin>> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+
+        if count == 0 || amount == 0 {
+            anyhow::bail!("The coin count and amount to split must be positive");
+        }
+
+        // Every transaction also needs the change output of the base asset
+        let per_tx = (provider.consensus_parameters().tx_params().max_outputs() as usize)
+            .saturating_sub(1)
+            .max(1);
+
+        // The coins already split must stay unspent, so no later split may select them
+        let mut split = vec![];
+        let mut remaining = count;
+        while remaining > 0 {
+            let chunk = remaining.min(per_tx);
+            remaining -= chunk;
+
+            let total = amount
+                .checked_mul(chunk as u64)
+                .ok_or_else(|| anyhow::anyhow!("The split amount overflows"))?;
+
+            // The fee is paid from the same inputs, so select more until they also cover it
+            let mut needed = total;
+            let tx = loop {
+                let inputs = account
+                    .get_asset_inputs_for_amount(base_asset_id, needed, Some(split.clone()))
+                    .await?;
+                let available: u64 = inputs.iter().filter_map(Input::amount).sum();
+                let mut outputs =
+                    vec![Output::coin(account.address().into(), amount, base_asset_id); chunk];
+                outputs.push(Output::change(account.address().into(), 0, base_asset_id));
+
+                let mut tx_builder =
+                    ScriptTransactionBuilder::prepare_transfer(inputs, outputs, self.tx_policies);
+                account.add_witnesses(&mut tx_builder)?;
+                let tx = tx_builder.build(provider).await?;
+
+                let required = total.saturating_add(tx.max_fee().unwrap_or_default());
+                if available >= required {
+                    break tx;
+                }
+                needed = required;
+            };
+
+            let tx_id = tx.id(provider.chain_id());
+            provider
+                .send_transaction_and_await_commit(tx)
+                .await?
+                .check(None)?;
+            // The split coins are the first outputs, the change comes after them
+            split.extend(
+                (0..chunk as u16).map(|index| CoinTypeId::UtxoId(UtxoId::new(tx_id, index))),
+            );
+        }
+
+        Ok(provider
+            .get_coins(account.address(), base_asset_id)
+            .await?
+            .into_iter()
+            .filter(|coin| split.contains(&CoinTypeId::UtxoId(coin.utxo_id)))
+            .collect())
+    }
+
+    /// Builds an unsent `mint_batch` call of `(recipient, asset, amount)` entries,
+    /// with a variable output for every entry minted to an address
+    pub fn mint_batch_call(&self, mints: &[(Identity, AssetId, u64)]) -> MultiAssetCall<()> {
+        let outputs = mints
+            .iter()
+            .filter(|(recipient, ..)| matches!(recipient, Identity::Address(_)))
+            .count();
+        self.instance
+            .methods()
+            .mint_batch(mints.to_vec())
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(outputs))
+            .with_tx_policies(self.tx_policies)
+    }
+
+    /// Mints every `(recipient, asset, amount)` entry in one transaction,
+    /// logging one `AssetMinted` per entry. Fails as a whole if any entry fails.
+    pub async fn mint_batch(
+        &self,
+        mints: &[(Identity, AssetId, u64)],
+    ) -> anyhow::Result<CallResponse<()>> {
+        self.send(self.mint_batch_call(mints)).await
+    }
+
+    /// Sends `mint_batch` without waiting for it, see `wait_for`
+    pub async fn submit_mint_batch(
+        &self,
+        mints: &[(Identity, AssetId, u64)],
+    ) -> anyhow::Result<TxId> {
+        self.submit(self.mint_batch_call(mints)).await
+    }
+
+    /// Simulates `mint_batch` and returns its gas and fee without sending it
+    pub async fn estimate_mint_batch(
+        &self,
+        mints: &[(Identity, AssetId, u64)],
+    ) -> anyhow::Result<CostEstimate> {
+        CostEstimate::of(self.mint_batch_call(mints)).await
+    }
+
+    /// Sends `asset` from the account's own balance to every `(recipient, amount)` instead of
+    /// minting more, see `distribute_assets`. Returns the ids of the sent transactions in order.
+    pub async fn distribute(
+        &self,
+        asset: &AssetId,
+        transfers: &[(Identity, u64)],
+    ) -> anyhow::Result<Vec<TxId>> {
+        let transfers: Vec<_> = transfers
+            .iter()
+            .map(|(recipient, amount)| (*recipient, *asset, *amount))
+            .collect();
+        self.distribute_assets(&transfers, |_, _| {}).await
+    }
+
+    /// Sends every `(recipient, asset, amount)` from the account's own balances. Addresses are
+    /// paid together across assets in as few transactions as the output limit allows, every
+    /// contract with its own transfer transaction. Fails before sending anything if a balance
+    /// does not cover its total, or the base asset balance its total and the estimated fees.
+    /// `on_commit` gets every transaction id with the transfers it paid as soon as it commits,
+    /// so a later failure does not hide them. Returns the ids of the sent transactions in order.
+    pub async fn distribute_assets(
+        &self,
+        transfers: &[(Identity, AssetId, u64)],
+        mut on_commit: impl FnMut(&TxId, &[(Identity, AssetId, u64)]),
+    ) -> anyhow::Result<Vec<TxId>> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+
+        if transfers.iter().any(|(.., amount)| *amount == 0) {
+            anyhow::bail!("The amount to distribute must be positive");
+        }
+        let totals = Self::asset_totals(transfers)?;
+        for (asset, total) in totals.iter() {
+            let balance = account.get_asset_balance(asset).await?;
+            if balance < *total {
+                anyhow::bail!(
+                    "Balance {} of 0x{} does not cover the distributed total {}",
+                    balance,
+                    asset,
+                    total
+                );
+            }
+        }
+
+        let (addresses, contracts): (Vec<_>, Vec<_>) = transfers
+            .iter()
+            .copied()
+            .partition(|(recipient, ..)| matches!(recipient, Identity::Address(_)));
+
+        // Every transaction also needs a change output for each asset it sends and the fee
+        let max_outputs = provider.consensus_parameters().tx_params().max_outputs() as usize;
+        let mut batches: Vec<Vec<(Identity, AssetId, u64)>> = vec![];
+        for transfer in addresses {
+            let fits = batches.last().is_some_and(|batch| {
+                let mut assets: Vec<AssetId> = batch.iter().map(|(_, asset, _)| *asset).collect();
+                assets.extend([transfer.1, base_asset_id]);
+                assets.sort();
+                assets.dedup();
+                batch.len() + 1 + assets.len() <= max_outputs
+            });
+            match batches.last_mut() {
+                Some(batch) if fits => batch.push(transfer),
+                _ => batches.push(vec![transfer]),
+            }
+        }
+        batches.extend(contracts.into_iter().map(|transfer| vec![transfer]));
+
+        // The fees are paid in the base asset too, so distributing it must leave enough for them
+        if let Some((_, total)) = totals.iter().find(|(asset, _)| *asset == base_asset_id) {
+            let mut fees = 0u64;
+            for batch in batches.iter() {
+                let tx = self.distribution_tx(batch).await?;
+                fees = fees.saturating_add(tx.max_fee().unwrap_or_default());
+            }
+            let balance = account.get_asset_balance(&base_asset_id).await?;
+            if balance < total.saturating_add(fees) {
+                anyhow::bail!(
+                    "Balance {} of 0x{} does not cover the distributed total {} and the estimated fees {}",
+                    balance,
+                    base_asset_id,
+                    total,
+                    fees
+                );
+            }
+        }
+
+        let mut tx_ids = vec![];
+        for batch in batches.iter() {
+            let tx = self.distribution_tx(batch).await?;
+            let tx_id = tx.id(provider.chain_id());
+            provider
+                .send_transaction_and_await_commit(tx)
+                .await?
+                .check(None)?;
+            on_commit(&tx_id, batch);
+            tx_ids.push(tx_id);
+        }
+
+        Ok(tx_ids)
+    }
+
+    /// Builds the transaction paying a batch of `distribute_assets`, either addresses
+    /// or a single contract
+    async fn distribution_tx(
+        &self,
+        batch: &[(Identity, AssetId, u64)],
+    ) -> anyhow::Result<ScriptTransaction> {
+        let account = self.instance.account();
+        let provider = account.try_provider()?;
+        let totals = Self::asset_totals(batch)?;
+
+        let mut tx_builder = match batch {
+            [(Identity::ContractId(contract_id), asset, amount)] => {
+                let zeroes = Bytes32::zeroed();
+                let mut inputs = vec![Input::contract(
+                    UtxoId::new(zeroes, 0),
+                    zeroes,
+                    zeroes,
+                    TxPointer::default(),
+                    *contract_id,
+                )];
+                inputs.extend(
+                    account
+                        .get_asset_inputs_for_amount(*asset, *amount, None)
+                        .await?,
+                );
+                let outputs = vec![
+                    Output::contract(0, zeroes, zeroes),
+                    Output::change(account.address().into(), 0, *asset),
+                ];
+                ScriptTransactionBuilder::prepare_contract_transfer(
+                    *contract_id,
+                    *amount,
+                    *asset,
+                    inputs,
+                    outputs,
+                    self.tx_policies,
+                )
+            }
+            _ => {
+                let mut inputs = vec![];
+                let mut outputs: Vec<Output> = batch
+                    .iter()
+                    .filter_map(|(recipient, asset, amount)| match recipient {
+                        Identity::Address(address) => Some(Output::coin(*address, *amount, *asset)),
+                        Identity::ContractId(_) => None,
+                    })
+                    .collect();
+                for (asset, total) in totals.iter() {
+                    inputs.extend(
+                        account
+                            .get_asset_inputs_for_amount(*asset, *total, None)
+                            .await?,
+                    );
+                    outputs.push(Output::change(account.address().into(), 0, *asset));
+                }
+                ScriptTransactionBuilder::prepare_transfer(inputs, outputs, self.tx_policies)
+            }
+        };
+
+        account.add_witnesses(&mut tx_builder)?;
+        let used_base_amount = totals
+            .iter()
+            .find(|(asset, _)| asset == provider.base_asset_id())
+            .map_or(0, |(_, total)| *total);
+        account
+            .adjust_for_fee(&mut tx_builder, used_base_amount)
+            .await?;
+
+        Ok(tx_builder.build(provider).await?)
+    }
+
+    /// The total of every asset in `transfers`, in the order the assets first appear
+    fn asset_totals(transfers: &[(Identity, AssetId, u64)]) -> anyhow::Result<Vec<(AssetId, u64)>> {
+        let mut totals: Vec<(AssetId, u64)> = vec![];
+        for (_, asset, amount) in transfers {
+            let index = match totals
+                .iter()
+                .position(|(total_asset, _)| total_asset == asset)
+            {
+                Some(index) => index,
+                None => {
+                    totals.push((*asset, 0));
+                    totals.len() - 1
+                }
+            };
+            totals[index].1 = totals[index]
+                .1
+                .checked_add(*amount)
+                .ok_or_else(|| anyhow::anyhow!("The amounts to distribute overflow"))?;
+        }
+        Ok(totals)
+    }
+
+    /// Builds an unsent `pause` call, e.g. to combine it with other calls
+    pub fn pause_call(&self, asset: Option<AssetId>) -> MultiAssetCall<()> {
+        self.instance
+            .methods()
+            .pause(asset)
+            .with_tx_policies(self.tx_policies)
+    }
+
+    pub async fn pause(&self, asset: Option<AssetId>) -> anyhow::Result<CallResponse<()>> {
+        self.send(self.pause_call(asset)).await
+    }
+
+    /// Sends `pause` without waiting for it, see `wait_for`
+    pub async fn submit_pause(&self, asset: Option<AssetId>) -> anyhow::Result<TxId> {
+        self.submit(self.pause_call(asset)).await
+    }
+
+    /// Builds an unsent `unpause` call, e.g. to combine it with other calls
+    pub fn unpause_call(&self, asset: Option<AssetId>) -> MultiAssetCall<()> {
+        self.instance
+            .methods()
+            .unpause(asset)
+            .with_tx_policies(self.tx_policies)
+    }
+
+    pub async fn unpause(&self, asset: Option<AssetId>) -> anyhow::Result<CallResponse<()>> {
+        self.send(self.unpause_call(asset)).await
+    }
+
+    /// Sends `unpause` without waiting for it, see `wait_for`
+    pub async fn submit_unpause(&self, asset: Option<AssetId>) -> anyhow::Result<TxId> {
+        self.submit(self.unpause_call(asset)).await
+    }
+
+    /// Builds an unsent `set_metadata` call, e.g. to combine it with other calls
+    pub fn set_metadata_call(
+        &self,
+        asset: &AssetId,
+        key: &str,
+        metadata: Metadata,
+    ) -> MultiAssetCall<()> {
+        self.instance
+            .methods()
+            .set_metadata(*asset, key.to_string(), metadata)
+            .with_tx_policies(self.tx_policies)
+    }
+
+    pub async fn set_metadata(
+        &self,
+        asset: &AssetId,
+        key: &str,
+        metadata: Metadata,
+    ) -> anyhow::Result<CallResponse<()>> {
+        self.send(self.set_metadata_call(asset, key, metadata))
+            .await
+    }
+
+    /// Sends `set_metadata` without waiting for it, see `wait_for`
+    pub async fn submit_set_metadata(
+        &self,
+        asset: &AssetId,
+        key: &str,
+        metadata: Metadata,
+    ) -> anyhow::Result<TxId> {
+        self.submit(self.set_metadata_call(asset, key, metadata))
+            .await
     }
 
     pub async fn total_assets(&self) -> anyhow::Result<CallResponse<u64>> {
@@ -138,8 +1471,8 @@ impl MultiAssetContract {
         Ok(self
             .instance
             .methods()
-            .total_supply(asset.clone())
-            .call()
+            .total_supply(*asset)
+            .simulate(Execution::StateReadOnly)
             .await?)
     }
 
@@ -147,7 +1480,7 @@ impl MultiAssetContract {
         Ok(self
             .instance
             .methods()
-            .name(asset.clone())
+            .name(*asset)
             .simulate(Execution::StateReadOnly)
             .await?)
     }
@@ -156,7 +1489,7 @@ impl MultiAssetContract {
         Ok(self
             .instance
             .methods()
-            .symbol(asset.clone())
+            .symbol(*asset)
             .simulate(Execution::StateReadOnly)
             .await?)
     }
@@ -165,16 +1498,25 @@ impl MultiAssetContract {
         Ok(self
             .instance
             .methods()
-            .decimals(asset.clone())
+            .decimals(*asset)
             .simulate(Execution::StateReadOnly)
             .await?)
     }
 
-    pub async fn asset(&self, name: &String) -> anyhow::Result<CallResponse<Option<AssetId>>> {
+    pub async fn asset(&self, name: &str) -> anyhow::Result<CallResponse<Option<AssetId>>> {
         Ok(self
             .instance
             .methods()
-            .asset(name.clone())
+            .asset(name.to_string())
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn owner(&self) -> anyhow::Result<CallResponse<State>> {
+        Ok(self
+            .instance
+            .methods()
+            .owner()
             .simulate(Execution::StateReadOnly)
             .await?)
     }
@@ -186,7 +1528,29 @@ impl MultiAssetContract {
         Ok(self
             .instance
             .methods()
-            .restricted_mint(asset.clone())
+            .restricted_mint(*asset)
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn metadata(
+        &self,
+        asset: &AssetId,
+        key: &str,
+    ) -> anyhow::Result<CallResponse<Option<Metadata>>> {
+        Ok(self
+            .instance
+            .methods()
+            .metadata(*asset, key.to_string())
+            .simulate(Execution::StateReadOnly)
+            .await?)
+    }
+
+    pub async fn is_paused(&self, asset: Option<AssetId>) -> anyhow::Result<CallResponse<bool>> {
+        Ok(self
+            .instance
+            .methods()
+            .is_paused(asset)
             .simulate(Execution::StateReadOnly)
             .await?)
     }
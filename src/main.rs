@@ -1,6 +1,8 @@
 mod commands;
 
 use clap::Parser;
+#[cfg(feature = "indexer")]
+use commands::indexer::cli::IndexerCommands;
 use commands::{
     cli::{Cli, Command},
     core::cli::CoreCommands,
@@ -17,19 +19,38 @@ async fn main() -> anyhow::Result<()> {
 
     match cli.command {
         Command::Core(args) => match args.commands {
+            CoreCommands::Apply(args) => args.run().await,
             CoreCommands::AssetNew(args) => args.run().await,
             CoreCommands::Deploy(args) => args.run().await,
+            CoreCommands::Distribute(args) => args.run().await,
             CoreCommands::Mint(args) => args.run().await,
+            CoreCommands::MintBatch(args) => args.run().await,
             CoreCommands::MintMany(args) => args.run().await,
+            CoreCommands::Pause(args) => args.run(false).await,
+            CoreCommands::SetMetadata(args) => args.run().await,
+            CoreCommands::Unpause(args) => args.run(true).await,
         },
         Command::Info(args) => match args.commands {
             InfoCommands::Bech32Conv(args) => args.run().await,
+            InfoCommands::ContractId(args) => args.run().await,
             InfoCommands::Decimals(args) => args.run().await,
+            InfoCommands::Deployment(args) => args.run().await,
+            InfoCommands::Diff(args) => args.run().await,
+            InfoCommands::History(args) => args.run().await,
+            InfoCommands::Holders(args) => args.run().await,
+            InfoCommands::Metadata(args) => args.run().await,
             InfoCommands::Name(args) => args.run().await,
             InfoCommands::RestrictedMint(args) => args.run().await,
             InfoCommands::Symbol(args) => args.run().await,
             InfoCommands::TotalAssets(args) => args.run().await,
             InfoCommands::TotalSupply(args) => args.run().await,
+            InfoCommands::Tx(args) => args.run().await,
+            InfoCommands::Watch(args) => args.run().await,
+        },
+        #[cfg(feature = "indexer")]
+        Command::Indexer(args) => match args.commands {
+            IndexerCommands::Holders(args) => args.run().await,
+            IndexerCommands::Run(args) => args.run().await,
         },
     }
 }
@@ -0,0 +1,146 @@
+use fuels::types::errors::{transaction::Reason, Error};
+use std::time::Duration;
+
+/// How many times a contract call is sent and how long to wait between attempts.
+/// The wait doubles after every failed attempt, up to `max_backoff`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub attempts: u32,
+    pub backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// A single attempt, never retried
+    fn default() -> Self {
+        Self {
+            attempts: 1,
+            backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(attempts: u32, backoff: Duration) -> Self {
+        Self {
+            attempts: attempts.max(1),
+            backoff,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// The wait after the failed `attempt`, counting from 1
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// Why sending a transaction failed, deciding whether it is sent again
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendFailure {
+    /// Its coins were spent by another transaction, e.g. a concurrent run from the same
+    /// wallet. Retried with freshly selected coins.
+    CoinConflict,
+    /// A timeout or unavailable node. Retried with the same transaction.
+    Transient,
+    /// A revert or invalid transaction, never retried
+    Fatal,
+}
+
+impl SendFailure {
+    pub fn of(error: &Error) -> Self {
+        let message = match error {
+            Error::Transaction(Reason::SqueezedOut(_)) => return Self::CoinConflict,
+            Error::Transaction(Reason::Reverted { .. })
+            | Error::Transaction(Reason::Builder(_))
+            | Error::Codec(_) => return Self::Fatal,
+            _ => error.to_string().to_lowercase(),
+        };
+
+        const CONFLICTS: [&str; 7] = [
+            "collided",
+            "already spent",
+            "utxo input does not exist",
+            "utxo (id",
+            "input coin does not match",
+            "squeezed",
+            "transaction is removed",
+        ];
+        const TRANSIENT: [&str; 9] = [
+            "timeout",
+            "timed out",
+            "connection",
+            "error sending request",
+            "queue is full",
+            "too much transactions",
+            "pool limit",
+            "service unavailable",
+            "too many requests",
+        ];
+        if CONFLICTS.iter().any(|marker| message.contains(marker)) {
+            Self::CoinConflict
+        } else if TRANSIENT.iter().any(|marker| message.contains(marker)) {
+            Self::Transient
+        } else {
+            Self::Fatal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_backoff() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(350));
+
+        assert_eq!(policy.attempts, 5);
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(350));
+        assert_eq!(policy.delay(40), Duration::from_millis(350));
+
+        // At least one attempt is always made
+        assert_eq!(RetryPolicy::new(0, Duration::ZERO).attempts, 1);
+        assert_eq!(RetryPolicy::default().attempts, 1);
+    }
+
+    #[test]
+    fn send_failure_classification() {
+        assert_eq!(
+            SendFailure::of(&Error::Transaction(Reason::SqueezedOut(
+                "coins spent".to_string()
+            ))),
+            SendFailure::CoinConflict
+        );
+        assert_eq!(
+            SendFailure::of(&Error::Provider("Transaction collided: 0x01".to_string())),
+            SendFailure::CoinConflict
+        );
+        assert_eq!(
+            SendFailure::of(&Error::Provider("operation timed out".to_string())),
+            SendFailure::Transient
+        );
+        assert_eq!(
+            SendFailure::of(&Error::IO("connection reset by peer".to_string())),
+            SendFailure::Transient
+        );
+        assert_eq!(
+            SendFailure::of(&Error::Transaction(Reason::Reverted {
+                reason: "NotOwner".to_string(),
+                revert_id: 0,
+                receipts: vec![],
+            })),
+            SendFailure::Fatal
+        );
+    }
+}
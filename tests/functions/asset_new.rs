@@ -1,6 +1,6 @@
 use crate::setup::setup;
 
-use multiasset_sdk::AssetNew;
+use multiasset_sdk::{AssetNew, MultiAssetEvent, SetDecimalsEvent, SetNameEvent, SetSymbolEvent};
 
 use fuels::types::AssetId;
 
@@ -43,6 +43,7 @@ mod success {
         assert_eq!(contract.symbol(&asset).await?.value, Some(symbol.clone()));
         assert_eq!(contract.decimals(&asset).await?.value, Some(decimals));
         assert_eq!(contract.asset(&symbol).await?.value, Some(asset));
+        assert_eq!(contract.asset_id(&symbol), asset);
         assert_eq!(
             contract.restricted_mint(&asset).await?.value,
             Some(restricted_mint)
@@ -50,6 +51,52 @@ mod success {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn create_asset_events() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+
+        let name = String::from("BTC_NAME");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let response = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?;
+        let asset = response.value;
+        let sender = owner.wallet.address().into();
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![
+                MultiAssetEvent::SetName(SetNameEvent {
+                    asset,
+                    name: Some(name.clone()),
+                    sender,
+                }),
+                MultiAssetEvent::SetSymbol(SetSymbolEvent {
+                    asset,
+                    symbol: Some(symbol.clone()),
+                    sender,
+                }),
+                MultiAssetEvent::SetDecimals(SetDecimalsEvent {
+                    asset,
+                    decimals,
+                    sender,
+                }),
+                MultiAssetEvent::AssetNew(AssetNew {
+                    asset,
+                    name,
+                    symbol,
+                    decimals,
+                    creator: sender,
+                }),
+            ]
+        );
+
+        Ok(())
+    }
 }
 
 mod revert {
@@ -0,0 +1,126 @@
+use crate::setup::setup;
+
+use multiasset_sdk::AssetNew;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn asset_new_batch() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let assets = [
+            ("Bitcoin", "BTC", 8, false),
+            ("USD Coin", "USDC", 6, false),
+            ("Ether", "ETH", 9, true),
+        ];
+
+        let response = contract.asset_new_batch(&assets).await?;
+
+        // Ids are returned in order
+        let expected: Vec<_> = assets
+            .iter()
+            .map(|(_, symbol, _, _)| contract.asset_id(symbol))
+            .collect();
+        assert_eq!(response.value, expected);
+        assert_eq!(contract.total_assets().await?.value, 3);
+
+        let events = response.decode_logs_with_type::<AssetNew>()?;
+        assert_eq!(events.len(), 3);
+        for ((name, symbol, decimals, restricted_mint), asset) in
+            assets.iter().zip(response.value.iter())
+        {
+            assert_eq!(contract.name(asset).await?.value, Some(name.to_string()));
+            assert_eq!(
+                contract.symbol(asset).await?.value,
+                Some(symbol.to_string())
+            );
+            assert_eq!(contract.decimals(asset).await?.value, Some(*decimals));
+            assert_eq!(
+                contract.restricted_mint(asset).await?.value,
+                Some(*restricted_mint)
+            );
+            assert_eq!(contract.asset(symbol).await?.value, Some(*asset));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn asset_new_batch_rolls_back() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        contract.asset_new("Bitcoin", "BTC", 8, false).await?;
+
+        // The last symbol exists, so none of the batch is created
+        let result = contract
+            .asset_new_batch(&[("USD Coin", "USDC", 6, false), ("Bitcoin", "BTC", 8, false)])
+            .await;
+        assert!(result.is_err());
+        assert_eq!(contract.total_assets().await?.value, 1);
+        assert_eq!(contract.asset("USDC").await?.value, None);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetAlreadyExists")]
+    async fn asset_new_batch_already_exists() {
+        let (contract, owner, _) = setup().await.unwrap();
+        let contract = contract.with_account(&owner.wallet);
+
+        contract
+            .asset_new("Bitcoin", "BTC", 8, false)
+            .await
+            .unwrap();
+
+        contract
+            .asset_new_batch(&[("USD Coin", "USDC", 6, false), ("Bitcoin", "BTC", 8, false)])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetAlreadyExists")]
+    async fn asset_new_batch_duplicate_symbol() {
+        let (contract, owner, _) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .asset_new_batch(&[("Bitcoin", "BTC", 8, false), ("Bitcoin", "BTC", 8, false)])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn asset_new_batch_not_owner() {
+        let (contract, _, user) = setup().await.unwrap();
+
+        contract
+            .with_account(&user.wallet)
+            .asset_new_batch(&[("Bitcoin", "BTC", 8, false)])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroValue")]
+    async fn asset_new_batch_empty() {
+        let (contract, owner, _) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .asset_new_batch(&[])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+use crate::setup::setup;
+
+use multiasset_sdk::{DeployConfig, Deployment, MultiAssetContract};
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::TxPolicies,
+    types::{Address, Bytes32, ContractId},
+};
+use std::path::PathBuf;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn deploy_with_record() -> anyhow::Result<()> {
+        let (_, owner, _) = setup().await?;
+        let provider = owner.wallet.try_provider()?.clone();
+        let salt = MultiAssetContract::salt_from_seed("deployment");
+
+        let (contract, deployment) =
+            MultiAssetContract::deploy_with_record(&owner.wallet, salt).await?;
+
+        assert_eq!(
+            deployment.contract_id,
+            ContractId::from(contract.contract_id())
+        );
+        assert_eq!(deployment.deployer, Address::from(owner.wallet.address()));
+        assert_eq!(deployment.network, provider.url());
+        assert!(deployment.block_height <= provider.latest_block_height().await?);
+        assert_eq!(deployment.verify(&provider).await?, Vec::<String>::new());
+
+        let path = std::env::temp_dir().join(format!("deployment-{}.json", contract.id()));
+        deployment.save(&path)?;
+        let loaded = Deployment::load(&path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded?, deployment);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deploy_with_salt() -> anyhow::Result<()> {
+        let (_, owner, _) = setup().await?;
+        let salt = MultiAssetContract::salt_from_seed("staging");
+        assert_eq!(salt, MultiAssetContract::salt_from_seed("staging"));
+        assert_ne!(salt, MultiAssetContract::salt_from_seed("production"));
+
+        let predicted = MultiAssetContract::predict_contract_id(salt)?;
+        let contract = MultiAssetContract::deploy_with_salt(&owner.wallet, salt).await?;
+
+        assert_eq!(ContractId::from(contract.contract_id()), predicted);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deploy_with_config() -> anyhow::Result<()> {
+        let (_, owner, _) = setup().await?;
+        let salt = MultiAssetContract::salt_from_seed("config");
+
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("contract/out/release");
+        let config = DeployConfig::new()
+            .with_binary_path(root.join("multiasset-contract.bin"))
+            .with_storage_slots_path(root.join("multiasset-contract-storage_slots.json"))
+            .with_salt(salt)
+            .with_tx_policies(TxPolicies::default().with_tip(1));
+
+        // The files on disk are the embedded artifacts
+        let predicted = MultiAssetContract::predict_contract_id(salt)?;
+        assert_eq!(config.contract_id()?, predicted);
+
+        let (contract, deployment) =
+            MultiAssetContract::deploy_with_config(&owner.wallet, &config).await?;
+
+        assert_eq!(ContractId::from(contract.contract_id()), predicted);
+        assert_eq!(deployment.contract_id, predicted);
+        assert_eq!(deployment.salt, salt);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_tampered_record() -> anyhow::Result<()> {
+        let (_, owner, _) = setup().await?;
+        let provider = owner.wallet.try_provider()?.clone();
+        let salt = MultiAssetContract::salt_from_seed("deployment");
+
+        let (_, deployment) = MultiAssetContract::deploy_with_record(&owner.wallet, salt).await?;
+        let tampered = Deployment {
+            bytecode_hash: Bytes32::zeroed(),
+            ..deployment.clone()
+        };
+
+        // Differs from both the contract on chain and its deployment transaction
+        assert_eq!(tampered.verify(&provider).await?.len(), 2);
+
+        let missing = Deployment {
+            contract_id: ContractId::zeroed(),
+            ..deployment
+        };
+        let mismatches = missing.verify(&provider).await?;
+        assert!(mismatches.contains(&String::from(
+            "contract 0x0000000000000000000000000000000000000000000000000000000000000000 not found"
+        )));
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Failed to read")]
+    async fn deploy_with_missing_binary() {
+        let (_, owner, _) = setup().await.unwrap();
+
+        let config = DeployConfig::new().with_binary_path("missing/multiasset-contract.bin");
+
+        MultiAssetContract::deploy_with_config(&owner.wallet, &config)
+            .await
+            .unwrap();
+    }
+}
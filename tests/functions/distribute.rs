@@ -0,0 +1,176 @@
+use crate::setup::setup;
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{Address, AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn distribute_to_addresses() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+        let provider = owner.wallet.try_provider()?.clone();
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let amount = 1_000_000_000;
+        contract
+            .mint(owner.wallet.address().into(), &asset, 3 * amount)
+            .await?;
+
+        let other = Address::from([7u8; 32]);
+        let transfers = [
+            (Identity::from(user.wallet.address()), amount),
+            (Identity::Address(other), amount),
+        ];
+
+        // Both addresses are paid in one transaction
+        let tx_ids = contract.distribute(&asset, &transfers).await?;
+        assert_eq!(tx_ids.len(), 1);
+
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, amount);
+        assert_eq!(
+            provider.get_asset_balance(&other.into(), asset).await?,
+            amount
+        );
+        assert_eq!(owner.wallet.get_asset_balance(&asset).await?, amount);
+
+        // Distributing moves balances, the supply is unchanged
+        assert_eq!(contract.total_supply(&asset).await?.value, Some(3 * amount));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn distribute_assets_in_one_transaction() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+        let provider = owner.wallet.try_provider()?.clone();
+
+        let btc = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let usdc = contract.asset_new("USDC", "USDC", 6, false).await?.value;
+        let amount = 1_000_000;
+        contract
+            .mint(owner.wallet.address().into(), &btc, amount)
+            .await?;
+        contract
+            .mint(owner.wallet.address().into(), &usdc, amount)
+            .await?;
+
+        let other = Address::from([7u8; 32]);
+        let transfers = [
+            (Identity::from(user.wallet.address()), btc, amount),
+            (Identity::Address(other), usdc, amount),
+        ];
+
+        // Both assets are paid in one transaction, reported once it commits
+        let mut committed = vec![];
+        let tx_ids = contract
+            .distribute_assets(&transfers, |tx_id, paid| {
+                committed.push((*tx_id, paid.len()))
+            })
+            .await?;
+        assert_eq!(tx_ids.len(), 1);
+        assert_eq!(committed, vec![(tx_ids[0], 2)]);
+
+        assert_eq!(user.wallet.get_asset_balance(&btc).await?, amount);
+        assert_eq!(
+            provider.get_asset_balance(&other.into(), usdc).await?,
+            amount
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn distribute_to_contract() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+        let provider = owner.wallet.try_provider()?.clone();
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let amount = 1_000_000_000;
+        contract
+            .mint(owner.wallet.address().into(), &asset, 2 * amount)
+            .await?;
+
+        let contract_id = contract.contract_id().clone();
+        let transfers = [
+            (Identity::from(user.wallet.address()), amount),
+            (Identity::ContractId((&contract_id).into()), amount),
+        ];
+
+        // One transaction for the addresses and one per contract
+        let tx_ids = contract.distribute(&asset, &transfers).await?;
+        assert_eq!(tx_ids.len(), 2);
+
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, amount);
+        assert_eq!(
+            provider
+                .get_contract_asset_balance(&contract_id, asset)
+                .await?,
+            amount
+        );
+        assert_eq!(owner.wallet.get_asset_balance(&asset).await?, 0);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "does not cover the distributed total")]
+    async fn distribute_above_balance() {
+        let (contract, owner, user) = setup().await.unwrap();
+        let contract = contract.with_account(&owner.wallet);
+
+        let asset = contract
+            .asset_new("BTC", "BTC", 8, false)
+            .await
+            .unwrap()
+            .value;
+        contract
+            .mint(owner.wallet.address().into(), &asset, 1)
+            .await
+            .unwrap();
+
+        contract
+            .distribute(&asset, &[(user.wallet.address().into(), 2)])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "and the estimated fees")]
+    async fn distribute_base_asset_without_fees() {
+        let (contract, owner, user) = setup().await.unwrap();
+        let base_asset = *owner.wallet.try_provider().unwrap().base_asset_id();
+
+        // The whole balance covers the transfer but leaves nothing for its fee
+        let balance = owner.wallet.get_asset_balance(&base_asset).await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .distribute(&base_asset, &[(user.wallet.address().into(), balance)])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "must be positive")]
+    async fn distribute_zero_amount() {
+        let (contract, owner, user) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .distribute(&AssetId::zeroed(), &[(user.wallet.address().into(), 0)])
+            .await
+            .unwrap();
+    }
+}
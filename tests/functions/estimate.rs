@@ -0,0 +1,92 @@
+use crate::setup::setup;
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn estimate() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let estimate = contract
+            .estimate_asset_new(&name, &symbol, decimals, false)
+            .await?;
+        assert!(estimate.gas_used > 0);
+        assert!(estimate.fee > 0);
+
+        // Estimating sends nothing
+        assert_eq!(contract.total_assets().await?.value, 0);
+
+        let asset = contract
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        let estimate = contract.estimate_mint(recipient, &asset, amount).await?;
+        assert!(estimate.gas_used > 0);
+        assert!(estimate.fee > 0);
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn estimate_mint_many_against_sent_mints() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+        let base_asset = *owner.wallet.try_provider()?.base_asset_id();
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        let batch = contract
+            .estimate_mint_many(recipient, &asset, amount, 3)
+            .await?;
+
+        let balance = owner.wallet.get_asset_balance(&base_asset).await?;
+        let mut gas_used = 0;
+        for _ in 0..3 {
+            gas_used += contract.mint(recipient, &asset, amount).await?.gas_used;
+        }
+        let fee = balance - owner.wallet.get_asset_balance(&base_asset).await?;
+
+        // The extrapolation stays within a tenth of the gas the mints used
+        assert!(gas_used.abs_diff(batch.gas_used) <= batch.gas_used / 10);
+        assert!(fee <= batch.fee);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetNotFound")]
+    async fn estimate_mint_unknown_asset() {
+        let (contract, owner, user) = setup().await.unwrap();
+
+        let recipient: Identity = user.wallet.address().into();
+
+        contract
+            .with_account(&owner.wallet)
+            .estimate_mint(recipient, &AssetId::zeroed(), 1)
+            .await
+            .unwrap();
+    }
+}
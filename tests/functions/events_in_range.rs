@@ -0,0 +1,63 @@
+use crate::setup::setup;
+
+use multiasset_sdk::MultiAssetEvent;
+
+use fuels::{accounts::ViewOnlyAccount, types::Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn events_in_range() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let provider = owner.wallet.try_provider()?.clone();
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let from = provider.latest_block_height().await?;
+
+        let response = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?;
+        let asset = response.value;
+        let mut expected = contract.events(&response)?;
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        let response = contract
+            .with_account(&owner.wallet)
+            .mint(recipient, &asset, amount)
+            .await?;
+        expected.extend(contract.events(&response)?);
+
+        let to = provider.latest_block_height().await?;
+
+        let events = contract.events_in_range(from, to).await?;
+        assert_eq!(
+            events
+                .iter()
+                .map(|block_event| block_event.event.clone())
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(events.last().unwrap().tx_id, response.tx_id.unwrap());
+        assert!(events
+            .iter()
+            .all(|block_event| (from..=to).contains(&block_event.block_height)));
+
+        let events = contract.events_in_range(to, to).await?;
+        assert!(events
+            .iter()
+            .all(|block_event| !matches!(block_event.event, MultiAssetEvent::AssetNew(_))));
+        assert!(events
+            .iter()
+            .any(|block_event| matches!(block_event.event, MultiAssetEvent::AssetMinted(_))));
+
+        Ok(())
+    }
+}
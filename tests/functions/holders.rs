@@ -0,0 +1,79 @@
+use crate::setup::setup;
+
+use multiasset_sdk::{Holder, MultiAssetContract};
+
+use fuels::{accounts::Account, prelude::TxPolicies, types::Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn holders() -> anyhow::Result<()> {
+        let (_, owner, user) = setup().await?;
+        let (contract, deployment) = MultiAssetContract::deploy_with_record(
+            &owner.wallet,
+            MultiAssetContract::salt_from_seed("holders"),
+        )
+        .await?;
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        assert_eq!(contract.holders(&asset, &deployment).await?, vec![]);
+
+        let amount = 1_000_000_000;
+        let owner_recipient: Identity = owner.wallet.address().into();
+        let user_recipient: Identity = user.wallet.address().into();
+
+        contract
+            .with_account(&owner.wallet)
+            .mint(user_recipient, &asset, amount)
+            .await?;
+        contract
+            .with_account(&owner.wallet)
+            .mint(user_recipient, &asset, amount)
+            .await?;
+        contract
+            .with_account(&owner.wallet)
+            .mint(owner_recipient, &asset, amount)
+            .await?;
+
+        // Balances drift from minted amounts once holders move tokens
+        let transferred = amount / 2;
+        owner
+            .wallet
+            .transfer(
+                user.wallet.address(),
+                transferred,
+                asset,
+                TxPolicies::default(),
+            )
+            .await?;
+
+        assert_eq!(
+            contract.holders(&asset, &deployment).await?,
+            vec![
+                Holder {
+                    recipient: user_recipient,
+                    minted: 2 * amount,
+                    balance: 2 * amount + transferred,
+                },
+                Holder {
+                    recipient: owner_recipient,
+                    minted: amount,
+                    balance: amount - transferred,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
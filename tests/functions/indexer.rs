@@ -0,0 +1,112 @@
+use crate::setup::setup;
+
+use multiasset_sdk::indexer::{IndexedAsset, Indexer};
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{ContractId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn indexer_sync() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let provider = owner.wallet.try_provider()?.clone();
+        let contract_id: ContractId = contract.contract_id().into();
+
+        let mut indexer = Indexer::open(":memory:", contract_id)?;
+        assert_eq!(indexer.cursor()?, None);
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+        let asset_height = provider.latest_block_height().await?;
+
+        let amount = 1_000_000_000;
+        let owner_recipient: Identity = owner.wallet.address().into();
+        let user_recipient: Identity = user.wallet.address().into();
+
+        contract
+            .with_account(&owner.wallet)
+            .mint(user_recipient, &asset, amount)
+            .await?;
+
+        let latest = provider.latest_block_height().await?;
+        assert_eq!(indexer.sync(&contract, 0, latest).await?, latest);
+        assert_eq!(indexer.cursor()?, Some(latest));
+
+        assert_eq!(
+            indexer.assets()?,
+            vec![IndexedAsset {
+                asset,
+                name,
+                symbol,
+                decimals,
+                block_height: asset_height,
+            }]
+        );
+        assert_eq!(indexer.holders(&asset)?, vec![(user_recipient, amount)]);
+
+        // Resumes after the cursor without counting old mints twice
+        contract
+            .with_account(&owner.wallet)
+            .mint(user_recipient, &asset, amount)
+            .await?;
+        contract
+            .with_account(&owner.wallet)
+            .mint(owner_recipient, &asset, amount)
+            .await?;
+
+        let latest = provider.latest_block_height().await?;
+        assert_eq!(indexer.sync(&contract, 0, latest).await?, latest);
+        assert_eq!(
+            indexer.holders(&asset)?,
+            vec![(user_recipient, 2 * amount), (owner_recipient, amount)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn indexer_sync_amounts_above_i64() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let provider = owner.wallet.try_provider()?.clone();
+        let contract_id: ContractId = contract.contract_id().into();
+        let contract = contract.with_account(&owner.wallet);
+
+        let mut indexer = Indexer::open(":memory:", contract_id)?;
+
+        let asset = contract.asset_new("BTC", "BTC", 12, false).await?.value;
+
+        // Each mint alone fits an i64, their total does not
+        let amount = i64::MAX as u64 - 1;
+        let owner_recipient: Identity = owner.wallet.address().into();
+        let user_recipient: Identity = user.wallet.address().into();
+        contract.mint(user_recipient, &asset, amount).await?;
+        contract.mint(user_recipient, &asset, amount).await?;
+        contract
+            .mint(owner_recipient, &asset, u64::MAX - 2 * amount)
+            .await?;
+
+        let latest = provider.latest_block_height().await?;
+        assert_eq!(indexer.sync(&contract, 0, latest).await?, latest);
+        assert_eq!(
+            indexer.holders(&asset)?,
+            vec![
+                (user_recipient, 2 * amount),
+                (owner_recipient, u64::MAX - 2 * amount)
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::setup::setup;
+
+use multiasset_sdk::{Metadata, MultiAssetEvent, SetMetadataEvent};
+
+use fuels::types::{AssetId, Bits256, Bytes};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn set_metadata() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        let key = "icon_url";
+        assert_eq!(contract.metadata(&asset, key).await?.value, None);
+
+        let icon_url = Metadata::String(String::from("https://example.com/btc.svg"));
+        let response = contract
+            .with_account(&owner.wallet)
+            .set_metadata(&asset, key, icon_url.clone())
+            .await?;
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![MultiAssetEvent::SetMetadata(SetMetadataEvent {
+                asset,
+                metadata: Some(icon_url.clone()),
+                key: key.to_string(),
+                sender: owner.wallet.address().into(),
+            })]
+        );
+
+        assert_eq!(contract.metadata(&asset, key).await?.value, Some(icon_url));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_metadata_types() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        let entries = [
+            (
+                "description",
+                Metadata::String(String::from("Test Bitcoin")),
+            ),
+            ("rank", Metadata::Int(1)),
+            ("logo", Metadata::Bytes(Bytes(vec![1, 2, 3]))),
+            ("hash", Metadata::B256(Bits256([7; 32]))),
+        ];
+
+        for (key, metadata) in entries.iter() {
+            contract
+                .with_account(&owner.wallet)
+                .set_metadata(&asset, key, metadata.clone())
+                .await?;
+        }
+
+        for (key, metadata) in entries.into_iter() {
+            assert_eq!(contract.metadata(&asset, key).await?.value, Some(metadata));
+        }
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn set_metadata_not_owner() {
+        let (contract, owner, user) = setup().await.unwrap();
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await
+            .unwrap()
+            .value;
+
+        contract
+            .with_account(&user.wallet)
+            .set_metadata(&asset, "icon_url", Metadata::Int(1))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetNotFound")]
+    async fn set_metadata_bad_asset() {
+        let (contract, owner, _) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .set_metadata(&AssetId::zeroed(), "icon_url", Metadata::Int(1))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "EmptyString")]
+    async fn set_metadata_empty_string() {
+        let (contract, owner, _) = setup().await.unwrap();
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await
+            .unwrap()
+            .value;
+
+        contract
+            .with_account(&owner.wallet)
+            .set_metadata(&asset, "icon_url", Metadata::String(String::new()))
+            .await
+            .unwrap();
+    }
+}
@@ -1,16 +1,56 @@
 use crate::setup::setup;
 
-use multiasset_sdk::AssetMinted;
+use multiasset_sdk::{AssetMinted, MultiAssetEvent, TotalSupplyEvent};
 
 use fuels::{
     accounts::ViewOnlyAccount,
-    types::{AssetId, Identity},
+    prelude::TxPolicies,
+    types::{
+        transaction::{Transaction, TransactionType},
+        AssetId, Identity,
+    },
 };
 
 mod success {
 
     use super::*;
 
+    #[tokio::test]
+    async fn mint_with_tx_policies() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let provider = owner.wallet.try_provider()?.clone();
+
+        let tip = 1_000;
+        let gas_limit = 1_000_000;
+        let contract = contract.with_account(&owner.wallet).with_tx_policies(
+            TxPolicies::default()
+                .with_tip(tip)
+                .with_script_gas_limit(gas_limit),
+        );
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        // The policies carry over to another account
+        let response = contract
+            .with_account(&user.wallet)
+            .mint(recipient, &asset, amount)
+            .await?;
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, amount);
+
+        let tx_id = response.tx_id.unwrap();
+        let committed = provider.get_transaction_by_id(&tx_id).await?.unwrap();
+        let TransactionType::Script(tx) = committed.transaction else {
+            panic!("mint is not a script transaction");
+        };
+        assert_eq!(tx.tip(), Some(tip));
+        assert_eq!(tx.gas_limit(), gas_limit);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn mint() -> anyhow::Result<()> {
         let (contract, minter, user) = setup().await?;
@@ -59,6 +99,53 @@ mod success {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn mint_events() -> anyhow::Result<()> {
+        let (contract, minter, user) = setup().await?;
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&minter.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+        let sender: Identity = minter.wallet.address().into();
+
+        let response = contract
+            .with_account(&minter.wallet)
+            .mint(recipient, &asset, amount)
+            .await?;
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![
+                MultiAssetEvent::TotalSupply(TotalSupplyEvent {
+                    asset,
+                    supply: amount,
+                    sender,
+                }),
+                MultiAssetEvent::AssetMinted(AssetMinted {
+                    recipient,
+                    asset,
+                    amount,
+                    minter: sender,
+                }),
+            ]
+        );
+        assert_eq!(
+            contract.tx_events(&response.tx_id.unwrap()).await?,
+            contract.events(&response)?
+        );
+
+        Ok(())
+    }
 }
 
 mod revert {
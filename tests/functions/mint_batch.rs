@@ -0,0 +1,118 @@
+use crate::setup::setup;
+
+use multiasset_sdk::AssetMinted;
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mint_batch() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let btc = contract.asset_new("Bitcoin", "BTC", 8, false).await?.value;
+        let usdc = contract
+            .asset_new("USD Coin", "USDC", 6, false)
+            .await?
+            .value;
+        let eth = contract.asset_new("Ether", "ETH", 9, false).await?.value;
+
+        let recipient: Identity = user.wallet.address().into();
+        let mints = vec![
+            (recipient, btc, 100_000_000),
+            (recipient, usdc, 1_000_000_000),
+            (recipient, eth, 10_000_000_000),
+        ];
+
+        let response = contract.mint_batch(&mints).await?;
+
+        let events = response.decode_logs_with_type::<AssetMinted>()?;
+        assert_eq!(
+            events,
+            mints
+                .iter()
+                .map(|(recipient, asset, amount)| AssetMinted {
+                    recipient: *recipient,
+                    asset: *asset,
+                    amount: *amount,
+                    minter: owner.wallet.address().into(),
+                })
+                .collect::<Vec<AssetMinted>>()
+        );
+        for (_, asset, amount) in mints.iter() {
+            assert_eq!(user.wallet.get_asset_balance(asset).await?, *amount);
+            assert_eq!(contract.total_supply(asset).await?.value, Some(*amount));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mint_batch_same_asset_twice() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+
+        let recipient: Identity = user.wallet.address().into();
+        let owner_id: Identity = owner.wallet.address().into();
+        let amount = 1_000_000_000;
+
+        contract
+            .mint_batch(&[
+                (recipient, asset, amount),
+                (owner_id, asset, amount),
+                (recipient, asset, amount),
+            ])
+            .await?;
+
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, 2 * amount);
+        assert_eq!(owner.wallet.get_asset_balance(&asset).await?, amount);
+        assert_eq!(contract.total_supply(&asset).await?.value, Some(3 * amount));
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroValue")]
+    async fn mint_batch_empty() {
+        let (contract, owner, _user) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .mint_batch(&[])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetNotFound")]
+    async fn mint_batch_unknown_asset_reverts_all() {
+        let (contract, owner, user) = setup().await.unwrap();
+        let contract = contract.with_account(&owner.wallet);
+
+        let asset = contract
+            .asset_new("BTC", "BTC", 8, false)
+            .await
+            .unwrap()
+            .value;
+
+        let recipient: Identity = user.wallet.address().into();
+
+        contract
+            .mint_batch(&[(recipient, asset, 1), (recipient, AssetId::zeroed(), 1)])
+            .await
+            .unwrap();
+    }
+}
@@ -1,3 +1,25 @@
+// The baseline tests predate the clippy gate and are kept as written
+#[allow(clippy::redundant_field_names)]
 mod asset_new;
+mod asset_new_batch;
+mod deployment;
+mod distribute;
+mod estimate;
+mod events_in_range;
+mod holders;
+#[cfg(feature = "indexer")]
+mod indexer;
+mod metadata;
+#[allow(
+    clippy::clone_on_copy,
+    clippy::redundant_field_names,
+    clippy::unnecessary_operation
+)]
 mod mint;
+mod mint_batch;
+mod multi_call;
+mod pause;
+mod retry;
+mod split_coins;
 mod transfer_ownership;
+mod wait_for;
@@ -0,0 +1,69 @@
+use crate::setup::setup;
+
+use multiasset_sdk::{AssetMinted, AssetNew, MultiAssetEvent};
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::{CallHandler, VariableOutputPolicy},
+    types::{AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn asset_new_and_mint_in_one_transaction() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        // The id is known before the asset exists, so the mint can join the same transaction
+        let asset = contract.asset_id(&symbol);
+
+        let response = CallHandler::new_multi_call(owner.wallet.clone())
+            .add_call(contract.asset_new_call(&name, &symbol, decimals, false))
+            .add_call(contract.mint_call(recipient, &asset, amount))
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .call::<(AssetId, ())>()
+            .await?;
+
+        assert_eq!(response.value.0, asset);
+        let events: Vec<MultiAssetEvent> = contract
+            .decode_events(&response.receipts)?
+            .into_iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    MultiAssetEvent::AssetNew(_) | MultiAssetEvent::AssetMinted(_)
+                )
+            })
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                MultiAssetEvent::AssetNew(AssetNew {
+                    asset,
+                    name,
+                    symbol,
+                    decimals,
+                    creator: owner.wallet.address().into(),
+                }),
+                MultiAssetEvent::AssetMinted(AssetMinted {
+                    recipient,
+                    asset,
+                    amount,
+                    minter: owner.wallet.address().into(),
+                }),
+            ]
+        );
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, amount);
+
+        Ok(())
+    }
+}
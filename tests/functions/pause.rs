@@ -0,0 +1,252 @@
+use crate::setup::setup;
+
+use multiasset_sdk::{MultiAssetEvent, Paused, Unpaused};
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pause_contract() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+
+        assert!(!contract.is_paused(None).await?.value);
+
+        let response = contract.with_account(&owner.wallet).pause(None).await?;
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![MultiAssetEvent::Paused(Paused {
+                asset: None,
+                sender: owner.wallet.address().into(),
+            })]
+        );
+        assert!(contract.is_paused(None).await?.value);
+
+        let response = contract.with_account(&owner.wallet).unpause(None).await?;
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![MultiAssetEvent::Unpaused(Unpaused {
+                asset: None,
+                sender: owner.wallet.address().into(),
+            })]
+        );
+        assert!(!contract.is_paused(None).await?.value);
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, 8, false)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pause_asset() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        let response = contract
+            .with_account(&owner.wallet)
+            .pause(Some(asset))
+            .await?;
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![MultiAssetEvent::Paused(Paused {
+                asset: Some(asset),
+                sender: owner.wallet.address().into(),
+            })]
+        );
+        assert!(contract.is_paused(Some(asset)).await?.value);
+        assert!(!contract.is_paused(None).await?.value);
+
+        contract
+            .with_account(&owner.wallet)
+            .unpause(Some(asset))
+            .await?;
+        assert!(!contract.is_paused(Some(asset)).await?.value);
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+        contract
+            .with_account(&user.wallet)
+            .mint(recipient, &asset, amount)
+            .await?;
+
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, amount);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pause_contract_pauses_assets() -> anyhow::Result<()> {
+        let (contract, owner, _) = setup().await?;
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await?
+            .value;
+
+        contract.with_account(&owner.wallet).pause(None).await?;
+
+        assert!(contract.is_paused(Some(asset)).await?.value);
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn pause_not_owner() {
+        let (contract, _, user) = setup().await.unwrap();
+
+        contract
+            .with_account(&user.wallet)
+            .pause(None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn unpause_not_owner() {
+        let (contract, owner, user) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .pause(None)
+            .await
+            .unwrap();
+
+        contract
+            .with_account(&user.wallet)
+            .unpause(None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetNotFound")]
+    async fn pause_bad_asset() {
+        let (contract, owner, _) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .pause(Some(AssetId::zeroed()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn asset_new_paused() {
+        let (contract, owner, _) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .pause(None)
+            .await
+            .unwrap();
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn mint_paused() {
+        let (contract, owner, user) = setup().await.unwrap();
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await
+            .unwrap()
+            .value;
+
+        contract
+            .with_account(&owner.wallet)
+            .pause(None)
+            .await
+            .unwrap();
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        contract
+            .with_account(&owner.wallet)
+            .mint(recipient, &asset, amount)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPaused")]
+    async fn mint_asset_paused() {
+        let (contract, owner, user) = setup().await.unwrap();
+
+        let name = String::from("BTC");
+        let symbol = String::from("BTC");
+        let decimals = 8;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new(&name, &symbol, decimals, false)
+            .await
+            .unwrap()
+            .value;
+
+        contract
+            .with_account(&owner.wallet)
+            .pause(Some(asset))
+            .await
+            .unwrap();
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        contract
+            .with_account(&user.wallet)
+            .mint(recipient, &asset, amount)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+use crate::setup::setup;
+
+use multiasset_sdk::RetryPolicy;
+
+use fuels::{accounts::ViewOnlyAccount, types::Identity};
+use std::time::Duration;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn concurrent_mints_from_one_wallet() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract
+            .with_account(&owner.wallet)
+            .with_retry_policy(RetryPolicy::new(5, Duration::from_millis(100)));
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+
+        let amount = 1_000_000_000;
+        let recipient: Identity = user.wallet.address().into();
+
+        // Both transactions start from the same coins, the loser is rebuilt and resent
+        let (first, second) = tokio::join!(
+            contract.mint(recipient, &asset, amount),
+            contract.mint(recipient, &asset, amount)
+        );
+        let (first, second) = (first?, second?);
+
+        assert!(first.tx_id.is_some());
+        assert_ne!(first.tx_id, second.tx_id);
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, 2 * amount);
+        assert_eq!(contract.total_supply(&asset).await?.value, Some(2 * amount));
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn revert_is_not_retried() {
+        let (contract, _, user) = setup().await.unwrap();
+
+        contract
+            .with_account(&user.wallet)
+            .with_retry_policy(RetryPolicy::new(3, Duration::from_secs(60)))
+            .asset_new("BTC", "BTC", 8, false)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+use crate::setup::setup;
+
+use fuels::{
+    accounts::ViewOnlyAccount,
+    types::{AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn split_coins() -> anyhow::Result<()> {
+        let (contract, owner, _user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let coins = contract.split_coins(3, 100_000).await?;
+
+        assert_eq!(coins.len(), 3);
+        assert!(coins.iter().all(|coin| coin.amount == 100_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_coins_across_transactions() -> anyhow::Result<()> {
+        let (contract, owner, _user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        // More coins than outputs in one transaction, the later splits must leave them unspent
+        let coins = contract.split_coins(300, 1_000).await?;
+
+        assert_eq!(coins.len(), 300);
+        assert!(coins.iter().all(|coin| coin.amount == 1_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mint_with_coin_returns_change() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+        let base_asset_id = *owner.wallet.try_provider()?.base_asset_id();
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let coin = contract.split_coins(1, 100_000_000).await?.remove(0);
+        let balance = owner.wallet.get_asset_balance(&base_asset_id).await?;
+
+        contract
+            .mint_with_coin(user.wallet.address().into(), &asset, 1_000, coin.clone())
+            .await?;
+
+        // Only the fee is spent, the rest of the coin comes back
+        let spent = balance - owner.wallet.get_asset_balance(&base_asset_id).await?;
+        assert!(spent > 0 && spent < coin.amount);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_mints_with_split_coins() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let recipient = Identity::from(user.wallet.address());
+        let amount = 1_000;
+
+        let fee = contract.estimate_mint(recipient, &asset, amount).await?.fee;
+        let mut coins = contract.split_coins(2, 2 * fee).await?;
+        let (second, first) = (coins.pop().unwrap(), coins.pop().unwrap());
+
+        // Each mint spends only its own coin, so neither conflicts with the other
+        let (first, second) = tokio::join!(
+            contract.mint_with_coin(recipient, &asset, amount, first),
+            contract.mint_with_coin(recipient, &asset, amount, second),
+        );
+        assert_ne!(first?.tx_id, second?.tx_id);
+
+        assert_eq!(user.wallet.get_asset_balance(&asset).await?, 2 * amount);
+        assert_eq!(contract.total_supply(&asset).await?.value, Some(2 * amount));
+
+        Ok(())
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "The coin count and amount to split must be positive")]
+    async fn split_no_coins() {
+        let (contract, owner, _user) = setup().await.unwrap();
+
+        contract
+            .with_account(&owner.wallet)
+            .split_coins(0, 100_000)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetNotFound")]
+    async fn mint_with_coin_bad_asset() {
+        let (contract, owner, user) = setup().await.unwrap();
+        let contract = contract.with_account(&owner.wallet);
+
+        let coin = contract.split_coins(1, 1_000_000).await.unwrap().remove(0);
+
+        contract
+            .mint_with_coin(
+                Identity::from(user.wallet.address()),
+                &AssetId::zeroed(),
+                1_000,
+                coin,
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 use crate::setup::setup;
 
-use multiasset_sdk::OwnershipTransferred;
+use multiasset_sdk::{MultiAssetEvent, OwnershipTransferred, State};
 
 mod success {
 
@@ -44,6 +44,38 @@ mod success {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn transfer_ownership_events() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+
+        assert_eq!(
+            contract.owner().await?.value,
+            State::Initialized(owner.wallet.address().into())
+        );
+
+        let response = contract
+            .with_account(&owner.wallet)
+            .transfer_ownership(user.wallet.address().into())
+            .await?;
+
+        assert_eq!(
+            contract.events(&response)?,
+            vec![MultiAssetEvent::OwnershipTransferred(
+                OwnershipTransferred {
+                    new_owner: user.wallet.address().into(),
+                    previous_owner: owner.wallet.address().into(),
+                }
+            )]
+        );
+
+        assert_eq!(
+            contract.owner().await?.value,
+            State::Initialized(user.wallet.address().into())
+        );
+
+        Ok(())
+    }
 }
 
 mod revert {
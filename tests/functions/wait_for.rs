@@ -0,0 +1,82 @@
+use crate::setup::setup;
+
+use fuels::types::Identity;
+use multiasset_sdk::{MultiAssetEvent, TxOutcome};
+use std::time::Duration;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_for_submitted_mint() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let asset = contract.asset_new("BTC", "BTC", 8, false).await?.value;
+        let recipient = Identity::from(user.wallet.address());
+
+        let tx_id = contract.submit_mint(recipient, &asset, 1_000).await?;
+        let outcome = contract.wait_for(&tx_id, Duration::from_secs(10)).await?;
+
+        let TxOutcome::Success { events, .. } = outcome else {
+            panic!("Expected a successful mint, got {:?}", outcome);
+        };
+        assert!(events.iter().any(|event| matches!(
+            event,
+            MultiAssetEvent::AssetMinted(event) if event.recipient == recipient && event.amount == 1_000
+        )));
+        assert_eq!(contract.total_supply(&asset).await?.value, Some(1_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_submitted_asset_new() -> anyhow::Result<()> {
+        let (contract, owner, _user) = setup().await?;
+        let contract = contract.with_account(&owner.wallet);
+
+        let tx_id = contract.submit_asset_new("BTC", "BTC", 8, false).await?;
+        contract.wait_for(&tx_id, Duration::from_secs(10)).await?;
+
+        // The outcome is kept after the commit
+        assert!(matches!(
+            contract.tx_outcome(&tx_id).await?,
+            Some(TxOutcome::Success { .. })
+        ));
+        assert_eq!(
+            contract.decimals(&contract.asset_id("BTC")).await?.value,
+            Some(8)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_for_reverted_mint() -> anyhow::Result<()> {
+        let (contract, owner, user) = setup().await?;
+
+        let asset = contract
+            .with_account(&owner.wallet)
+            .asset_new("BTC", "BTC", 8, true)
+            .await?
+            .value;
+
+        // A restricted asset minted by another account reverts with the decoded reason
+        let contract = contract.with_account(&user.wallet);
+        let tx_id = contract
+            .submit_mint(Identity::from(user.wallet.address()), &asset, 1_000)
+            .await?;
+        let outcome = contract.wait_for(&tx_id, Duration::from_secs(10)).await?;
+
+        let TxOutcome::Reverted { reason, .. } = outcome else {
+            panic!("Expected a reverted mint, got {:?}", outcome);
+        };
+        assert!(reason.contains("NotOwner"));
+
+        // Logs of the reverted mint never took effect
+        assert!(contract.tx_events(&tx_id).await?.is_empty());
+
+        Ok(())
+    }
+}